@@ -1,7 +1,8 @@
 extern crate rand;
 
-use rand::Rng;
+use rand::{Rng, SeedableRng, StdRng};
 use std::cmp::Ordering;
+use std::env;
 use std::io;
 
 fn main() {
@@ -9,7 +10,17 @@ fn main() {
     let mut prev = 0;
     let mut trolling_count = 0;
 
-    let secret_number = rand::thread_rng().gen_range(1, 101);
+    // 시드를 주면 매번 같은 수가 나옴: cargo run -- 42
+    let seed: Option<usize> = env::args()
+        .nth(1)
+        .and_then(|seed| seed.parse().ok());
+    let secret_number = match seed {
+        Some(seed) => {
+            let mut rng = StdRng::from_seed(&[seed][..]);
+            rng.gen_range(1, 101)
+        }
+        None => rand::thread_rng().gen_range(1, 101),
+    };
 
     loop {
         println!("추측한 수를 입력하세요: ");
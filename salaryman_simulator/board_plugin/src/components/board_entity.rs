@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// 타일맵으로부터 생성된 엔티티. 타일맵이 바뀌면 지우고 다시 생성합니다.
+#[derive(Debug, Default, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct BoardEntity;
@@ -0,0 +1,26 @@
+use std::fmt::{self, Display, Formatter};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 타일맵 위의 타일 좌표 (왼쪽 아래가 (0, 0))
+#[derive(
+    Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Component, Reflect, Serialize, Deserialize,
+)]
+#[reflect(Component)]
+pub struct Coordinates {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl Coordinates {
+    pub fn new(x: u16, y: u16) -> Self {
+        Coordinates { x, y }
+    }
+}
+
+impl Display for Coordinates {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
@@ -0,0 +1,5 @@
+pub use board_entity::BoardEntity;
+pub use coordinates::Coordinates;
+
+mod board_entity;
+mod coordinates;
@@ -1 +1,105 @@
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+
+use resources::{BoardLayout, BoardOptions, OfficeLayout, OfficeLayoutLoader, TileMap};
+
+/// 사무실 평면도를 타일 단위로 관리하는 플러그인
 pub struct BoardPlugin;
+
+impl Plugin for BoardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<OfficeLayout>()
+            .init_asset_loader::<OfficeLayoutLoader>()
+            // 다른 플러그인의 Startup 시스템이 타일맵을 읽을 수 있도록 먼저 생성
+            .add_systems(PreStartup, Self::create_board)
+            .add_systems(Update, (Self::apply_layout, Self::fall_back_on_failed_layout));
+    }
+}
+
+impl BoardPlugin {
+    pub fn create_board(
+        mut commands: Commands,
+        board_options: Option<Res<BoardOptions>>,
+        asset_server: Res<AssetServer>,
+    ) {
+        let options = match board_options {
+            None => BoardOptions::default(),
+            Some(o) => o.clone(),
+        };
+
+        // 배치도 파일이 있으면 로드가 끝났을 때 타일맵을 생성
+        if let Some(layout_path) = options.layout_path {
+            commands.insert_resource(BoardLayout(asset_server.load(layout_path)));
+            return;
+        }
+
+        let tile_map = TileMap::office(options.map_size.0, options.map_size.1, options.tile_size);
+        #[cfg(feature = "debug")]
+        // 콘솔에 타일맵 출력
+        info!("{}", tile_map.console_output());
+
+        commands.insert_resource(tile_map);
+    }
+
+    /// 배치도가 로드되거나 파일이 수정되면 타일맵을 다시 생성
+    pub fn apply_layout(
+        mut commands: Commands,
+        mut events: EventReader<AssetEvent<OfficeLayout>>,
+        board_layout: Option<Res<BoardLayout>>,
+        layouts: Res<Assets<OfficeLayout>>,
+    ) {
+        let Some(board_layout) = board_layout else {
+            return;
+        };
+
+        for event in events.read() {
+            let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = event
+            else {
+                continue;
+            };
+            if *id != board_layout.0.id() {
+                continue;
+            }
+            let Some(layout) = layouts.get(*id) else {
+                continue;
+            };
+
+            let tile_map = TileMap::from_layout(layout);
+            info!("Office layout applied: {}", layout.name);
+            #[cfg(feature = "debug")]
+            // 콘솔에 타일맵 출력
+            info!("{}", tile_map.console_output());
+
+            commands.insert_resource(tile_map);
+        }
+    }
+
+    /// 배치도를 읽지 못하면 기본 사무실로 대신함. 이미 타일맵이 있으면 그대로 둠
+    pub fn fall_back_on_failed_layout(
+        mut commands: Commands,
+        board_options: Option<Res<BoardOptions>>,
+        board_layout: Option<Res<BoardLayout>>,
+        tile_map: Option<Res<TileMap>>,
+        asset_server: Res<AssetServer>,
+    ) {
+        let Some(board_layout) = board_layout else {
+            return;
+        };
+        if tile_map.is_some() || asset_server.load_state(&board_layout.0) != LoadState::Failed {
+            return;
+        }
+
+        let options = match board_options {
+            None => BoardOptions::default(),
+            Some(o) => o.clone(),
+        };
+        error!(
+            "Could not load office layout {:?}, falling back to the default office",
+            options.layout_path
+        );
+        commands.insert_resource(TileMap::office(options.map_size.0, options.map_size.1, options.tile_size));
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// 타일맵 생성 옵션. 앱에 리소스로 넣지 않으면 기본값을 사용합니다.
+#[derive(Debug, Clone, Resource)]
+pub struct BoardOptions {
+    /// 타일 개수 (가로, 세로)
+    pub map_size: (u16, u16),
+    /// 타일 한 칸의 월드 크기
+    pub tile_size: f32,
+    /// assets 폴더 기준 배치도 파일 경로. 없으면 기본 사무실을 생성합니다.
+    pub layout_path: Option<String>,
+}
+
+impl Default for BoardOptions {
+    fn default() -> Self {
+        BoardOptions {
+            map_size: (16, 16),
+            tile_size: 50.0,
+            layout_path: None,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub use board_options::BoardOptions;
+pub use office_layout::{BoardLayout, NpcSpawn, ObjectSpawn, OfficeLayout, OfficeLayoutLoader};
+pub use tile::Tile;
+pub use tile_map::TileMap;
+
+mod board_options;
+mod office_layout;
+mod tile;
+mod tile_map;
@@ -0,0 +1,111 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::components::Coordinates;
+
+/// 파일에서 읽어오는 사무실 배치도
+///
+/// `tiles`는 위쪽 줄부터 한 글자씩 적습니다.
+/// `#` 벽, `.` 바닥, `D` 책상, `+` 문, `M` 회의실
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct OfficeLayout {
+    pub name: String,
+    pub tile_size: f32,
+    pub tiles: Vec<String>,
+    /// `tiles`와 별도로 책상을 놓을 좌표
+    #[serde(default)]
+    pub desks: Vec<Coordinates>,
+    #[serde(default)]
+    pub npcs: Vec<NpcSpawn>,
+    /// 프린터, 커피 머신처럼 상호작용할 수 있는 물건
+    #[serde(default)]
+    pub objects: Vec<ObjectSpawn>,
+}
+
+/// 배치도에 지정된 동료의 시작 위치와 직장 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcSpawn {
+    pub name: String,
+    pub position: Coordinates,
+    /// 연봉 (만원)
+    pub salary: u32,
+    pub role: String,
+    /// 직급 (사원, 대리, 과장...). 비어 있으면 사원
+    #[serde(default)]
+    pub rank: String,
+    /// 업무를 나눠주는 관리자인지
+    #[serde(default)]
+    pub manager: bool,
+}
+
+/// 배치도에 지정된 물건의 종류와 위치
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectSpawn {
+    /// 물건 종류 (coffee_machine, printer, elevator, boss_door)
+    pub kind: String,
+    /// 비어 있으면 종류 이름을 씀
+    #[serde(default)]
+    pub name: String,
+    pub position: Coordinates,
+    /// 물건에서 할 수 있는 행동을 적은 RON 문자열. 내용은 배치도를 쓰는 게임이 해석합니다.
+    /// 비어 있으면 종류마다 정해진 행동을 씀
+    #[serde(default)]
+    pub actions: String,
+}
+
+/// 현재 사용 중인 배치도 핸들
+#[derive(Debug, Clone, Resource)]
+pub struct BoardLayout(pub Handle<OfficeLayout>);
+
+#[derive(Default)]
+pub struct OfficeLayoutLoader;
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum OfficeLayoutLoaderError {
+    #[error("Could not load layout: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse RON layout: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Could not parse JSON layout: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl AssetLoader for OfficeLayoutLoader {
+    type Asset = OfficeLayout;
+    type Settings = ();
+    type Error = OfficeLayoutLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await?;
+
+            let is_json = load_context
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "json");
+            let layout = if is_json {
+                serde_json::from_slice::<OfficeLayout>(&bytes)?
+            } else {
+                ron::de::from_bytes::<OfficeLayout>(&bytes)?
+            };
+            Ok(layout)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["layout.ron", "layout.json"]
+    }
+}
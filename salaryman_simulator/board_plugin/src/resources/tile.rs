@@ -0,0 +1,48 @@
+#[cfg(feature = "debug")]
+use colored::Colorize;
+
+use bevy::prelude::*;
+
+/// 사무실 바닥 한 칸의 종류
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Reflect)]
+pub enum Tile {
+    #[default]
+    Floor,
+    Wall,
+    Desk,
+    Door,
+    MeetingRoom,
+}
+
+impl Tile {
+    /// 사람이 지나갈 수 있는 타일인지
+    pub const fn is_walkable(&self) -> bool {
+        !matches!(self, Self::Wall | Self::Desk)
+    }
+
+    /// 배치도 파일에서 쓰는 한 글자 표기
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Floor),
+            '#' => Some(Self::Wall),
+            'D' => Some(Self::Desk),
+            '+' => Some(Self::Door),
+            'M' => Some(Self::MeetingRoom),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "debug")]
+    pub fn console_output(&self) -> String {
+        format!(
+            "{}",
+            match self {
+                Tile::Floor => " ".normal(),
+                Tile::Wall => "#".bright_black(),
+                Tile::Desk => "D".yellow(),
+                Tile::Door => "+".green(),
+                Tile::MeetingRoom => "M".cyan(),
+            }
+        )
+    }
+}
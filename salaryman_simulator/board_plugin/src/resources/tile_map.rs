@@ -0,0 +1,248 @@
+use bevy::prelude::*;
+
+use crate::components::Coordinates;
+use crate::resources::office_layout::OfficeLayout;
+use crate::resources::tile::Tile;
+
+/// 사무실 평면도. 월드 원점이 맵의 중앙에 오도록 배치됩니다.
+#[derive(Debug, Clone, Resource)]
+pub struct TileMap {
+    width: u16,
+    height: u16,
+    tile_size: f32,
+    map: Vec<Vec<Tile>>,
+}
+
+impl TileMap {
+    /// 모든 칸이 바닥인 빈 맵
+    pub fn empty(width: u16, height: u16, tile_size: f32) -> Self {
+        let map = (0..height)
+            .map(|_| (0..width).map(|_| Tile::Floor).collect())
+            .collect();
+        TileMap {
+            width,
+            height,
+            tile_size,
+            map,
+        }
+    }
+
+    /// 외벽, 아래쪽 출입문, 오른쪽 위 회의실, 아래쪽 책상 두 줄로 된 기본 사무실
+    pub fn office(width: u16, height: u16, tile_size: f32) -> Self {
+        let mut tile_map = Self::empty(width, height, tile_size);
+
+        for y in 0..height {
+            for x in 0..width {
+                if x == 0 || y == 0 || x == width.saturating_sub(1) || y == height.saturating_sub(1) {
+                    tile_map.set(Coordinates::new(x, y), Tile::Wall);
+                }
+            }
+        }
+        tile_map.set(Coordinates::new(width / 2, 0), Tile::Door);
+
+        for y in height.saturating_sub(5)..height.saturating_sub(1) {
+            for x in width.saturating_sub(5)..width.saturating_sub(1) {
+                tile_map.set(Coordinates::new(x, y), Tile::MeetingRoom);
+            }
+        }
+
+        for y in [2, 5] {
+            for x in (2..width.saturating_sub(2)).step_by(3) {
+                tile_map.set(Coordinates::new(x, y), Tile::Desk);
+            }
+        }
+
+        tile_map
+    }
+
+    /// 배치도 파일로부터 생성. 가장 긴 줄이 맵의 너비가 되고, 모르는 글자는 바닥으로 처리합니다.
+    pub fn from_layout(layout: &OfficeLayout) -> Self {
+        let width = layout
+            .tiles
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0) as u16;
+        let height = layout.tiles.len() as u16;
+        let mut tile_map = Self::empty(width, height, layout.tile_size);
+
+        // 파일은 위쪽 줄부터 적혀 있으므로 뒤집어서 아래쪽이 y = 0이 되게 함
+        for (y, row) in layout
+            .tiles
+            .iter()
+            .rev()
+            .enumerate()
+        {
+            for (x, c) in row.chars().enumerate() {
+                let tile = Tile::from_char(c).unwrap_or_default();
+                tile_map.set(Coordinates::new(x as u16, y as u16), tile);
+            }
+        }
+        for desk in layout.desks.iter() {
+            tile_map.set(*desk, Tile::Desk);
+        }
+
+        tile_map
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn tile_size(&self) -> f32 {
+        self.tile_size
+    }
+
+    pub fn get(&self, coordinates: Coordinates) -> Option<Tile> {
+        self.map
+            .get(coordinates.y as usize)?
+            .get(coordinates.x as usize)
+            .copied()
+    }
+
+    /// 맵 밖의 좌표는 무시합니다.
+    pub fn set(&mut self, coordinates: Coordinates, tile: Tile) {
+        if let Some(cell) = self
+            .map
+            .get_mut(coordinates.y as usize)
+            .and_then(|row| row.get_mut(coordinates.x as usize))
+        {
+            *cell = tile;
+        }
+    }
+
+    /// 특정 종류의 타일 좌표를 모두 반환
+    pub fn tiles_of(&self, tile: Tile) -> impl Iterator<Item = Coordinates> + '_ {
+        self.map
+            .iter()
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(move |(_, t)| **t == tile)
+                    .map(move |(x, _)| Coordinates::new(x as u16, y as u16))
+            })
+    }
+
+    /// 맵 전체의 월드 크기
+    pub fn world_size(&self) -> Vec2 {
+        Vec2::new(
+            self.width as f32 * self.tile_size,
+            self.height as f32 * self.tile_size,
+        )
+    }
+
+    /// 타일 중심의 월드 위치
+    pub fn tile_to_world(&self, coordinates: Coordinates) -> Vec2 {
+        Vec2::new(
+            (coordinates.x as f32 + 0.5) * self.tile_size,
+            (coordinates.y as f32 + 0.5) * self.tile_size,
+        ) - self.world_size() / 2.0
+    }
+
+    pub fn tile_to_transform(&self, coordinates: Coordinates) -> Transform {
+        Transform::from_translation(
+            self.tile_to_world(coordinates)
+                .extend(0.0),
+        )
+    }
+
+    /// 월드 위치가 속한 타일. 맵 밖이면 None
+    pub fn world_to_tile(&self, position: Vec2) -> Option<Coordinates> {
+        let local = (position + self.world_size() / 2.0) / self.tile_size;
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+        let (x, y) = (local.x as u16, local.y as u16);
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(Coordinates::new(x, y))
+    }
+
+    pub fn transform_to_tile(&self, transform: &Transform) -> Option<Coordinates> {
+        self.world_to_tile(
+            transform
+                .translation
+                .truncate(),
+        )
+    }
+
+    #[cfg(feature = "debug")]
+    pub fn console_output(&self) -> String {
+        let mut buffer = format!("Map ({}, {}):\n", self.width, self.height);
+        let line: String = (0..=(self.width + 1))
+            .map(|_| '-')
+            .collect();
+        buffer = format!("{}{}\n", buffer, line);
+        // 위쪽 줄부터 출력해서 화면과 같은 방향으로 보이게 함
+        for row in self
+            .map
+            .iter()
+            .rev()
+        {
+            buffer = format!("{}|", buffer);
+            for tile in row.iter() {
+                buffer = format!("{}{}", buffer, tile.console_output());
+            }
+            buffer = format!("{}|\n", buffer);
+        }
+        format!("{}{}", buffer, line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn office_with_zero_size_is_empty() {
+        for (width, height) in [(0, 0), (0, 5), (5, 0), (1, 1)] {
+            let tile_map = TileMap::office(width, height, 32.0);
+            assert_eq!(tile_map.width(), width);
+            assert_eq!(tile_map.height(), height);
+        }
+        assert_eq!(TileMap::office(0, 0, 32.0).get(Coordinates::new(0, 0)), None);
+    }
+
+    #[test]
+    fn office_has_outer_walls_and_door() {
+        let tile_map = TileMap::office(20, 15, 32.0);
+        assert_eq!(tile_map.get(Coordinates::new(0, 7)), Some(Tile::Wall));
+        assert_eq!(tile_map.get(Coordinates::new(19, 7)), Some(Tile::Wall));
+        assert_eq!(tile_map.get(Coordinates::new(3, 14)), Some(Tile::Wall));
+        assert_eq!(tile_map.get(Coordinates::new(10, 0)), Some(Tile::Door));
+    }
+
+    #[test]
+    fn tile_to_world_and_world_to_tile_are_inverses() {
+        let tile_map = TileMap::office(20, 15, 32.0);
+        for y in 0..tile_map.height() {
+            for x in 0..tile_map.width() {
+                let coordinates = Coordinates::new(x, y);
+                assert_eq!(tile_map.world_to_tile(tile_map.tile_to_world(coordinates)), Some(coordinates));
+            }
+        }
+        // 타일 중심에서 반 칸 안쪽은 같은 타일
+        let center = tile_map.tile_to_world(Coordinates::new(4, 6));
+        assert_eq!(tile_map.world_to_tile(center + Vec2::splat(15.0)), Some(Coordinates::new(4, 6)));
+        assert_eq!(tile_map.world_to_tile(center - Vec2::splat(15.0)), Some(Coordinates::new(4, 6)));
+    }
+
+    #[test]
+    fn world_to_tile_outside_map_is_none() {
+        let tile_map = TileMap::office(20, 15, 32.0);
+        let half = tile_map.world_size() / 2.0;
+        assert_eq!(tile_map.world_to_tile(Vec2::new(-half.x - 1.0, 0.0)), None);
+        assert_eq!(tile_map.world_to_tile(Vec2::new(0.0, -half.y - 1.0)), None);
+        assert_eq!(tile_map.world_to_tile(Vec2::new(half.x, 0.0)), None);
+        assert_eq!(tile_map.world_to_tile(Vec2::new(0.0, half.y)), None);
+        assert_eq!(tile_map.world_to_tile(Vec2::new(half.x - 1.0, half.y - 1.0)), Some(Coordinates::new(19, 14)));
+        assert_eq!(tile_map.world_to_tile(-half), Some(Coordinates::new(0, 0)));
+        assert_eq!(TileMap::office(0, 0, 32.0).world_to_tile(Vec2::ZERO), None);
+    }
+}
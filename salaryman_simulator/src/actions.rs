@@ -0,0 +1,723 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::gamepad::{GamepadAxisType, Gamepads};
+use bevy::input::mouse::MouseMotion;
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::tick::{TickInput, UiInputFocus};
+
+/// 키 설정 파일. 키 설정 화면에서 바꾸면 여기에 저장됩니다.
+pub const INPUT_CONFIG_PATH: &str = "config/input.ron";
+
+/// 키나 버튼에 연결할 수 있는 게임 동작
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Interact,
+    /// 주변에 상호작용 대상이 여럿이면 다음 대상 고르기
+    CycleTarget,
+    /// 선택지 고르기
+    Confirm,
+    /// 선택지 닫기
+    Cancel,
+    ChoiceUp,
+    ChoiceDown,
+    /// 키 설정 화면 열기
+    OpenMenu,
+    TogglePayslip,
+    Pause,
+    SlowDown,
+    SpeedUp,
+    /// 저장 슬롯 고르기
+    SelectSlot1,
+    SelectSlot2,
+    SelectSlot3,
+    SaveGame,
+    LoadGame,
+}
+
+impl Action {
+    pub const ALL: [Action; 20] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Interact,
+        Action::CycleTarget,
+        Action::Confirm,
+        Action::Cancel,
+        Action::ChoiceUp,
+        Action::ChoiceDown,
+        Action::OpenMenu,
+        Action::TogglePayslip,
+        Action::Pause,
+        Action::SlowDown,
+        Action::SpeedUp,
+        Action::SelectSlot1,
+        Action::SelectSlot2,
+        Action::SelectSlot3,
+        Action::SaveGame,
+        Action::LoadGame,
+    ];
+
+    /// 저장 슬롯 순서대로 슬롯을 고르는 동작
+    pub const SELECT_SLOTS: [Action; 3] = [Action::SelectSlot1, Action::SelectSlot2, Action::SelectSlot3];
+
+    /// 힌트 문장에서 `{이름}`으로 쓰는 영문 이름
+    pub fn id(&self) -> &'static str {
+        match self {
+            Action::MoveUp => "MoveUp",
+            Action::MoveDown => "MoveDown",
+            Action::MoveLeft => "MoveLeft",
+            Action::MoveRight => "MoveRight",
+            Action::Interact => "Interact",
+            Action::CycleTarget => "CycleTarget",
+            Action::Confirm => "Confirm",
+            Action::Cancel => "Cancel",
+            Action::ChoiceUp => "ChoiceUp",
+            Action::ChoiceDown => "ChoiceDown",
+            Action::OpenMenu => "OpenMenu",
+            Action::TogglePayslip => "TogglePayslip",
+            Action::Pause => "Pause",
+            Action::SlowDown => "SlowDown",
+            Action::SpeedUp => "SpeedUp",
+            Action::SelectSlot1 => "SelectSlot1",
+            Action::SelectSlot2 => "SelectSlot2",
+            Action::SelectSlot3 => "SelectSlot3",
+            Action::SaveGame => "SaveGame",
+            Action::LoadGame => "LoadGame",
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Action::MoveUp => "위로 이동",
+            Action::MoveDown => "아래로 이동",
+            Action::MoveLeft => "왼쪽으로 이동",
+            Action::MoveRight => "오른쪽으로 이동",
+            Action::Interact => "상호작용",
+            Action::CycleTarget => "다음 상호작용 대상",
+            Action::Confirm => "선택지 고르기",
+            Action::Cancel => "선택지 닫기",
+            Action::ChoiceUp => "이전 선택지",
+            Action::ChoiceDown => "다음 선택지",
+            Action::OpenMenu => "키 설정",
+            Action::TogglePayslip => "급여 명세서",
+            Action::Pause => "일시정지",
+            Action::SlowDown => "시간 배율 절반",
+            Action::SpeedUp => "시간 배율 두 배",
+            Action::SelectSlot1 => "저장 슬롯 1",
+            Action::SelectSlot2 => "저장 슬롯 2",
+            Action::SelectSlot3 => "저장 슬롯 3",
+            Action::SaveGame => "저장",
+            Action::LoadGame => "불러오기",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 동작에 연결된 키보드 키나 게임패드 버튼
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Binding {
+    Key(KeyCode),
+    Gamepad(GamepadButtonType),
+}
+
+impl Binding {
+    pub fn is_gamepad(&self) -> bool {
+        matches!(self, Binding::Gamepad(_))
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Binding::Key(key) => {
+                let name = format!("{:?}", key);
+                let name = name
+                    .strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .unwrap_or(&name);
+                match name {
+                    "ArrowUp" => write!(f, "↑"),
+                    "ArrowDown" => write!(f, "↓"),
+                    "ArrowLeft" => write!(f, "←"),
+                    "ArrowRight" => write!(f, "→"),
+                    "BracketLeft" => write!(f, "["),
+                    "BracketRight" => write!(f, "]"),
+                    name => write!(f, "{}", name),
+                }
+            }
+            // 엑스박스 패드 표기
+            Binding::Gamepad(button) => match button {
+                GamepadButtonType::South => write!(f, "패드 A"),
+                GamepadButtonType::East => write!(f, "패드 B"),
+                GamepadButtonType::West => write!(f, "패드 X"),
+                GamepadButtonType::North => write!(f, "패드 Y"),
+                GamepadButtonType::DPadUp => write!(f, "패드 ↑"),
+                GamepadButtonType::DPadDown => write!(f, "패드 ↓"),
+                GamepadButtonType::DPadLeft => write!(f, "패드 ←"),
+                GamepadButtonType::DPadRight => write!(f, "패드 →"),
+                button => write!(f, "패드 {:?}", button),
+            },
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ActionMapError {
+    #[error("Could not access input config: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse input config: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("Could not write input config: {0}")]
+    Serialize(#[from] ron::Error),
+}
+
+/// 이동에 쓰는 왼쪽 스틱의 데드존 (스틱을 기울인 정도, 0 ~ 1)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct StickDeadzone {
+    /// 이보다 덜 기울이면 움직이지 않음
+    pub inner: f32,
+    /// 이보다 더 기울이면 최고 속도
+    pub outer: f32,
+}
+
+impl Default for StickDeadzone {
+    fn default() -> Self {
+        StickDeadzone {
+            inner: 0.2,
+            outer: 0.9,
+        }
+    }
+}
+
+impl StickDeadzone {
+    /// 방향은 그대로 두고, inner에서 0, outer에서 1이 되도록 기울기를 다시 맞춤
+    pub fn apply(&self, stick: Vec2) -> Vec2 {
+        let length = stick.length();
+        if length <= self.inner {
+            return Vec2::ZERO;
+        }
+        let strength = ((length - self.inner) / (self.outer - self.inner).max(f32::EPSILON)).min(1.0);
+        stick / length * strength
+    }
+}
+
+/// 동작별로 연결된 키와 버튼
+///
+/// 입력 기록에도 함께 저장되어서, 키 설정이 달라도 기록한 입력은 같은 동작으로 재생됩니다.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ActionMap {
+    bindings: BTreeMap<Action, Vec<Binding>>,
+    #[serde(default)]
+    pub stick_deadzone: StickDeadzone,
+}
+
+impl Default for ActionMap {
+    fn default() -> Self {
+        let mut bindings = BTreeMap::new();
+        for action in Action::ALL {
+            bindings.insert(action, default_bindings(action));
+        }
+        ActionMap {
+            bindings,
+            stick_deadzone: StickDeadzone::default(),
+        }
+    }
+}
+
+impl ActionMap {
+    /// 설정 파일을 읽음. 파일에 없는 동작은 기본 키를 씁니다.
+    pub fn load(path: &Path) -> Result<Self, ActionMapError> {
+        let text = fs::read_to_string(path)?;
+        let mut map: ActionMap = ron::de::from_str(&text)?;
+        for action in Action::ALL {
+            map.bindings
+                .entry(action)
+                .or_insert_with(|| default_bindings(action));
+        }
+        Ok(map)
+    }
+
+    /// 설정 파일이 없거나 읽을 수 없으면 기본 키 설정
+    pub fn load_or_default(path: &Path) -> Self {
+        match ActionMap::load(path) {
+            Ok(map) => map,
+            Err(ActionMapError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => ActionMap::default(),
+            Err(error) => {
+                warn!("Using default key bindings: {}", error);
+                ActionMap::default()
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ActionMapError> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        if let Some(directory) = path
+            .parent()
+            .filter(|directory| !directory.as_os_str().is_empty())
+        {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(path, text)?;
+        Ok(())
+    }
+
+    pub fn bindings(&self, action: Action) -> &[Binding] {
+        self.bindings
+            .get(&action)
+            .map(|bindings| bindings.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// 같은 종류(키보드 또는 게임패드)의 기존 연결을 새 키로 바꿈
+    pub fn rebind(&mut self, action: Action, binding: Binding) {
+        let bindings = self
+            .bindings
+            .entry(action)
+            .or_default();
+        bindings.retain(|bound| bound.is_gamepad() != binding.is_gamepad());
+        bindings.push(binding);
+    }
+
+    pub fn reset(&mut self, action: Action) {
+        self.bindings
+            .insert(action, default_bindings(action));
+    }
+
+    /// 화면에 표시할 키 이름. 마지막으로 쓴 입력 장치의 키를 먼저 씁니다.
+    pub fn label(&self, action: Action, device: InputDevice) -> String {
+        let mut bindings: Vec<&Binding> = self
+            .bindings(action)
+            .iter()
+            .collect();
+        bindings.sort_by_key(|binding| binding.is_gamepad() != (device == InputDevice::Gamepad));
+        match bindings.first() {
+            Some(binding) => binding.to_string(),
+            None => "-".to_string(),
+        }
+    }
+
+    /// 힌트 문장의 `{Interact}` 같은 자리에 지금 연결된 키 이름을 채움
+    pub fn fill_hint(&self, template: &str, device: InputDevice) -> String {
+        let mut text = template.to_string();
+        for action in Action::ALL {
+            let placeholder = format!("{{{}}}", action.id());
+            if text.contains(&placeholder) {
+                text = text.replace(&placeholder, &self.label(action, device));
+            }
+        }
+        text
+    }
+}
+
+fn default_bindings(action: Action) -> Vec<Binding> {
+    use Binding::{Gamepad, Key};
+    match action {
+        Action::MoveUp => vec![Key(KeyCode::KeyW)],
+        Action::MoveDown => vec![Key(KeyCode::KeyS)],
+        Action::MoveLeft => vec![Key(KeyCode::KeyA)],
+        Action::MoveRight => vec![Key(KeyCode::KeyD)],
+        Action::Interact => vec![Key(KeyCode::KeyE), Gamepad(GamepadButtonType::South)],
+        Action::CycleTarget => vec![Key(KeyCode::KeyQ), Gamepad(GamepadButtonType::RightTrigger)],
+        Action::Confirm => vec![
+            Key(KeyCode::Enter),
+            Key(KeyCode::NumpadEnter),
+            Gamepad(GamepadButtonType::South),
+        ],
+        Action::Cancel => vec![Key(KeyCode::Escape), Gamepad(GamepadButtonType::East)],
+        Action::ChoiceUp => vec![Key(KeyCode::ArrowUp), Gamepad(GamepadButtonType::DPadUp)],
+        Action::ChoiceDown => vec![Key(KeyCode::ArrowDown), Gamepad(GamepadButtonType::DPadDown)],
+        Action::OpenMenu => vec![Key(KeyCode::F10), Gamepad(GamepadButtonType::Start)],
+        Action::TogglePayslip => vec![Key(KeyCode::Tab), Gamepad(GamepadButtonType::Select)],
+        Action::Pause => vec![Key(KeyCode::KeyP)],
+        Action::SlowDown => vec![Key(KeyCode::BracketLeft)],
+        Action::SpeedUp => vec![Key(KeyCode::BracketRight)],
+        Action::SelectSlot1 => vec![Key(KeyCode::F1)],
+        Action::SelectSlot2 => vec![Key(KeyCode::F2)],
+        Action::SelectSlot3 => vec![Key(KeyCode::F3)],
+        Action::SaveGame => vec![Key(KeyCode::F5)],
+        Action::LoadGame => vec![Key(KeyCode::F9)],
+    }
+}
+
+/// 틱 단위로 본 왼쪽 스틱 기울기 (데드존을 적용한 값)
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct MoveStick(pub Vec2);
+
+/// 마지막으로 입력한 장치. 힌트에 표시할 키와 선택지 강조 방식을 정합니다.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InputDevice {
+    #[default]
+    KeyboardMouse,
+    Gamepad,
+}
+
+/// 시뮬레이션 틱에서 읽는 동작 입력. 틱 단위 입력(TickInput)을 동작으로 바꿔 봅니다.
+#[derive(SystemParam)]
+pub struct ActionInput<'w> {
+    map: Res<'w, ActionMap>,
+    keys: Res<'w, TickInput<KeyCode>>,
+    gamepad_buttons: Res<'w, TickInput<GamepadButtonType>>,
+    stick: Res<'w, MoveStick>,
+}
+
+impl ActionInput<'_> {
+    /// 동작에 연결하지 않은 고정 키 (선택지 번호 키)
+    pub fn keys(&self) -> &ButtonInput<KeyCode> {
+        &self.keys
+    }
+
+    pub fn pressed(&self, action: Action) -> bool {
+        self.map
+            .bindings(action)
+            .iter()
+            .any(|binding| match binding {
+                Binding::Key(key) => self.keys.pressed(*key),
+                Binding::Gamepad(button) => self
+                    .gamepad_buttons
+                    .pressed(*button),
+            })
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.map
+            .bindings(action)
+            .iter()
+            .any(|binding| match binding {
+                Binding::Key(key) => self.keys.just_pressed(*key),
+                Binding::Gamepad(button) => self
+                    .gamepad_buttons
+                    .just_pressed(*button),
+            })
+    }
+
+    /// 이동 방향과 세기 (길이 0 ~ 1). 이동 키는 길이 1로 맞추고, 누르지 않으면 스틱 기울기를 씁니다.
+    pub fn movement(&self) -> Vec2 {
+        let mut direction = Vec2::ZERO;
+        if self.pressed(Action::MoveUp) {
+            direction.y += 1.0;
+        }
+        if self.pressed(Action::MoveLeft) {
+            direction.x -= 1.0;
+        }
+        if self.pressed(Action::MoveDown) {
+            direction.y -= 1.0;
+        }
+        if self.pressed(Action::MoveRight) {
+            direction.x += 1.0;
+        }
+        if direction.length_squared() > 0.0 {
+            direction.normalize()
+        } else {
+            self.stick.0
+        }
+    }
+}
+
+/// 화면 UI에서 프레임마다 읽는 동작 입력 (급여 명세서, 저장, 키 설정 화면)
+#[derive(SystemParam)]
+pub struct UiActionInput<'w> {
+    map: Res<'w, ActionMap>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<'w, ButtonInput<GamepadButton>>,
+    device: Res<'w, InputDevice>,
+}
+
+impl UiActionInput<'_> {
+    pub fn map(&self) -> &ActionMap {
+        &self.map
+    }
+
+    /// 마지막으로 쓴 입력 장치의 키 이름으로 힌트 문장을 채움
+    pub fn hint(&self, template: &str) -> String {
+        self.map
+            .fill_hint(template, *self.device)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.map
+            .bindings(action)
+            .iter()
+            .any(|binding| match binding {
+                Binding::Key(key) => self.keys.just_pressed(*key),
+                Binding::Gamepad(button_type) => self
+                    .gamepad_buttons
+                    .get_just_pressed()
+                    .any(|button| button.button_type == *button_type),
+            })
+    }
+
+    /// 이번 프레임에 처음 누른 키나 버튼 (키 설정 화면에서 새 키를 받을 때)
+    pub fn any_just_pressed(&self) -> Option<Binding> {
+        self.keys
+            .get_just_pressed()
+            .next()
+            .map(|key| Binding::Key(*key))
+            .or_else(|| {
+                self.gamepad_buttons
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| Binding::Gamepad(button.button_type))
+            })
+    }
+}
+
+/// 키 설정 화면에서 동작의 키를 바꿈. `binding`이 없으면 기본 키로 되돌립니다.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RebindAction {
+    pub action: Action,
+    pub binding: Option<Binding>,
+}
+
+pub struct ActionsPlugin;
+
+impl Plugin for ActionsPlugin {
+    fn build(&self, app: &mut App) {
+        // 입력을 재생할 때는 기록에 담긴 키 설정을 미리 넣어 둠
+        if !app
+            .world
+            .contains_resource::<ActionMap>()
+        {
+            app.insert_resource(ActionMap::load_or_default(Path::new(INPUT_CONFIG_PATH)));
+        }
+        app.init_resource::<MoveStick>()
+            .init_resource::<InputDevice>()
+            .add_event::<RebindAction>()
+            .add_systems(
+                PreUpdate,
+                (collect_move_stick, track_input_device)
+                    .chain()
+                    .after(InputSystem),
+            )
+            .add_systems(Update, apply_rebinding);
+    }
+}
+
+// 연결된 게임패드 중 가장 많이 기울인 왼쪽 스틱을 다음 틱의 이동 입력으로 넘김
+fn collect_move_stick(
+    focus: Res<UiInputFocus>,
+    map: Res<ActionMap>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    mut stick: ResMut<MoveStick>,
+) {
+    let mut tilt = Vec2::ZERO;
+    if !focus.0 {
+        for gamepad in gamepads.iter() {
+            let gamepad_tilt = Vec2::new(
+                axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+                    .unwrap_or(0.0),
+                axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))
+                    .unwrap_or(0.0),
+            );
+            if gamepad_tilt.length_squared() > tilt.length_squared() {
+                tilt = gamepad_tilt;
+            }
+        }
+    }
+    let tilt = map
+        .stick_deadzone
+        .apply(tilt);
+    if stick.0 != tilt {
+        stick.0 = tilt;
+    }
+}
+
+// 게임패드를 만지면 게임패드, 키보드나 마우스를 쓰면 키보드/마우스로 바꿈
+fn track_input_device(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    stick: Res<MoveStick>,
+    mut device: ResMut<InputDevice>,
+) {
+    let moved_mouse = mouse_motion
+        .read()
+        .count()
+        > 0;
+    let used = if gamepad_buttons
+        .get_just_pressed()
+        .next()
+        .is_some()
+        || stick.0 != Vec2::ZERO
+    {
+        InputDevice::Gamepad
+    } else if keys
+        .get_just_pressed()
+        .next()
+        .is_some()
+        || mouse_buttons
+            .get_just_pressed()
+            .next()
+            .is_some()
+        || moved_mouse
+    {
+        InputDevice::KeyboardMouse
+    } else {
+        return;
+    };
+    if *device != used {
+        *device = used;
+    }
+}
+
+// 바꾼 키 설정을 적용하고 설정 파일에 저장
+fn apply_rebinding(
+    mut events: EventReader<RebindAction>,
+    mut map: ResMut<ActionMap>,
+) {
+    let mut changed = false;
+    for event in events.read() {
+        match event.binding {
+            Some(binding) => map.rebind(event.action, binding),
+            None => map.reset(event.action),
+        }
+        changed = true;
+    }
+    if !changed {
+        return;
+    }
+    match map.save(Path::new(INPUT_CONFIG_PATH)) {
+        Ok(()) => info!("Saved key bindings to {}", INPUT_CONFIG_PATH),
+        Err(error) => error!("Could not save key bindings: {}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::gamepad::{
+        GamepadAxisChangedEvent, GamepadButtonChangedEvent, GamepadConnection, GamepadConnectionEvent, GamepadEvent,
+        GamepadInfo,
+    };
+    use bevy::input::InputPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::gui::components::ChoiceUI;
+    use crate::gui::{choice_input, ChoiceDismissed, ChoiceSelected};
+    use crate::tick::{TickPlugin, TickRate};
+
+    const GAMEPAD: Gamepad = Gamepad { id: 0 };
+
+    /// 틱에서 상호작용 동작을 받은 횟수
+    #[derive(Resource, Default)]
+    struct Interactions(u32);
+
+    fn count_interactions(actions: ActionInput, mut interactions: ResMut<Interactions>) {
+        if actions.just_pressed(Action::Interact) {
+            interactions.0 += 1;
+        }
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.insert_resource(ActionMap::default())
+            .add_plugins((MinimalPlugins, InputPlugin, ActionsPlugin, TickPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(TickRate::default().timestep()))
+            .init_resource::<Interactions>()
+            .add_event::<ChoiceSelected>()
+            .add_event::<ChoiceDismissed>()
+            .add_systems(FixedUpdate, (count_interactions, choice_input));
+        // 첫 업데이트는 시간이 흐르지 않음
+        app.update();
+        app.world
+            .send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+                GAMEPAD,
+                GamepadConnection::Connected(GamepadInfo {
+                    name: "테스트 패드".to_string(),
+                }),
+            )));
+        app.update();
+        app
+    }
+
+    /// 버튼을 한 틱 동안 눌렀다가 다음 틱에 뗌
+    fn tap(app: &mut App, button_type: GamepadButtonType) {
+        for value in [1.0, 0.0] {
+            app.world
+                .send_event(GamepadEvent::Button(GamepadButtonChangedEvent::new(
+                    GAMEPAD,
+                    button_type,
+                    value,
+                )));
+            app.update();
+        }
+    }
+
+    #[test]
+    fn left_stick_is_scaled_by_deadzone() {
+        let mut app = test_app();
+        for (tilt, expected) in [(0.1, 0.0), (0.55, 0.5), (1.0, 1.0)] {
+            app.world
+                .send_event(GamepadEvent::Axis(GamepadAxisChangedEvent::new(
+                    GAMEPAD,
+                    GamepadAxisType::LeftStickX,
+                    tilt,
+                )));
+            app.update();
+            let stick = app
+                .world
+                .resource::<MoveStick>()
+                .0;
+            assert!((stick.x - expected).abs() < 1e-5, "{} -> {:?}", tilt, stick);
+            assert_eq!(stick.y, 0.0);
+        }
+        assert_eq!(*app.world.resource::<InputDevice>(), InputDevice::Gamepad);
+    }
+
+    #[test]
+    fn south_button_interacts() {
+        let mut app = test_app();
+        tap(&mut app, GamepadButtonType::South);
+        assert_eq!(
+            app.world
+                .resource::<Interactions>()
+                .0,
+            1
+        );
+        assert_eq!(*app.world.resource::<InputDevice>(), InputDevice::Gamepad);
+    }
+
+    #[test]
+    fn dpad_moves_choice_selection() {
+        let mut app = test_app();
+        let ui = app
+            .world
+            .spawn(ChoiceUI {
+                prompt: String::new(),
+                choices: vec!["하나".to_string(), "둘".to_string(), "셋".to_string()],
+                selected: 0,
+                is_visible: true,
+                tranform: Transform::default(),
+            })
+            .id();
+        let selected = |app: &App| {
+            app.world
+                .get::<ChoiceUI>(ui)
+                .unwrap()
+                .selected
+        };
+
+        tap(&mut app, GamepadButtonType::DPadDown);
+        assert_eq!(selected(&app), 1);
+        tap(&mut app, GamepadButtonType::DPadUp);
+        tap(&mut app, GamepadButtonType::DPadUp);
+        assert_eq!(selected(&app), 2);
+    }
+}
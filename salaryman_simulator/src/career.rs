@@ -0,0 +1,597 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::clock::DayStarted;
+use crate::components::Worker;
+use crate::dialogue::DialogueEnded;
+use crate::money::Money;
+use crate::player::Player;
+use crate::relationships::{reputation, Relationships};
+use crate::tasks::{Performance, TaskList};
+
+/// 이만큼 승진 점수가 쌓이면 승진
+const PROMOTION_POINTS: u32 = 3;
+/// 이만큼 경고가 쌓이면 강등, 사원이면 해고
+const WARNING_POINTS: u32 = 3;
+const PROMOTION_RAISE: f64 = 0.10;
+const DEMOTION_CUT: f64 = 0.05;
+/// 평가 점수에서 동료 관계가 차지하는 비율
+const RELATIONSHIP_WEIGHT: f32 = 0.2;
+/// 한 분기에 이만큼 대화하면 관계 점수 만점
+const FULL_RELATIONSHIP_CONVERSATIONS: u32 = 10;
+
+/// 직급
+#[derive(Reflect, Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[reflect(Component)]
+pub enum Rank {
+    #[default]
+    Staff,
+    AssistantManager,
+    Manager,
+    DeputyGeneralManager,
+    GeneralManager,
+    Director,
+}
+
+impl Rank {
+    const ALL: [Rank; 6] = [
+        Rank::Staff,
+        Rank::AssistantManager,
+        Rank::Manager,
+        Rank::DeputyGeneralManager,
+        Rank::GeneralManager,
+        Rank::Director,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::Staff => "사원",
+            Rank::AssistantManager => "대리",
+            Rank::Manager => "과장",
+            Rank::DeputyGeneralManager => "차장",
+            Rank::GeneralManager => "부장",
+            Rank::Director => "이사",
+        }
+    }
+
+    /// 배치도에 적힌 한글 직급. 모르는 이름이면 사원
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|rank| rank.name() == name)
+            .unwrap_or_default()
+    }
+
+    pub fn next(&self) -> Option<Rank> {
+        Self::ALL
+            .get(*self as usize + 1)
+            .copied()
+    }
+
+    pub fn previous(&self) -> Option<Rank> {
+        (*self as usize)
+            .checked_sub(1)
+            .map(|index| Self::ALL[index])
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// 직군
+#[derive(Reflect, Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[reflect(Component)]
+pub enum JobFamily {
+    Engineering,
+    Design,
+    Sales,
+    Management,
+    #[default]
+    Office,
+}
+
+impl JobFamily {
+    pub fn from_role(role: &str) -> Self {
+        match role {
+            "개발" => JobFamily::Engineering,
+            "디자인" => JobFamily::Design,
+            "영업" => JobFamily::Sales,
+            "관리" => JobFamily::Management,
+            _ => JobFamily::Office,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            JobFamily::Engineering => "기술",
+            JobFamily::Design => "디자인",
+            JobFamily::Sales => "영업",
+            JobFamily::Management => "경영관리",
+            JobFamily::Office => "사무",
+        }
+    }
+}
+
+impl fmt::Display for JobFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// 인사 평가 등급
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Grade {
+    S,
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Grade {
+    /// 한 분기 동안의 업무 성과와 동료 관계로 등급을 매김. 업무를 받지 않았으면 평가하지 않음
+    ///
+    /// 동료 관계는 나눈 대화 수와 동료들의 평판(0 ~ 1)을 반씩 봅니다.
+    pub fn from_quarter(completed: u32, missed: u32, conversations: u32, reputation: f32) -> Option<Self> {
+        let total = completed + missed;
+        if total == 0 {
+            return None;
+        }
+        let task_rate = completed as f32 / total as f32;
+        let conversation_rate = (conversations as f32 / FULL_RELATIONSHIP_CONVERSATIONS as f32).min(1.0);
+        let relationship = (conversation_rate + reputation.clamp(0.0, 1.0)) / 2.0;
+        let score = task_rate * (1.0 - RELATIONSHIP_WEIGHT) + relationship * RELATIONSHIP_WEIGHT;
+        let grade = match score {
+            score if score >= 0.9 => Grade::S,
+            score if score >= 0.75 => Grade::A,
+            score if score >= 0.55 => Grade::B,
+            score if score >= 0.35 => Grade::C,
+            _ => Grade::D,
+        };
+        Some(grade)
+    }
+
+    /// 연봉 인상률
+    pub fn raise(&self) -> f64 {
+        match self {
+            Grade::S => 0.08,
+            Grade::A => 0.05,
+            Grade::B => 0.02,
+            Grade::C => 0.0,
+            Grade::D => -0.03,
+        }
+    }
+
+    fn promotion_points(&self) -> u32 {
+        match self {
+            Grade::S => 2,
+            Grade::A => 1,
+            _ => 0,
+        }
+    }
+
+    fn warning_points(&self) -> u32 {
+        match self {
+            Grade::C => 1,
+            Grade::D => 2,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for Grade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// 평가 결과 직급 변화
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CareerOutcome {
+    Stayed,
+    Promoted(Rank),
+    Demoted(Rank),
+    Fired,
+}
+
+#[derive(Reflect, Debug, Clone)]
+pub struct PerformanceReview {
+    /// 평가한 날 (게임 시작일로부터 지난 날 수)
+    pub day: u32,
+    pub completed: u32,
+    pub missed: u32,
+    pub conversations: u32,
+    pub grade: Grade,
+    pub outcome: CareerOutcome,
+}
+
+/// 인사 기록. 지난 평가 이후의 성과만 다음 평가에 반영합니다.
+#[derive(Reflect, Component, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct CareerRecord {
+    pub completed_at_last_review: u32,
+    pub missed_at_last_review: u32,
+    pub promotion_points: u32,
+    pub warning_points: u32,
+    /// 지난 평가 이후 동료와 나눈 대화 수
+    pub conversations: u32,
+    pub reviews: Vec<PerformanceReview>,
+}
+
+impl CareerRecord {
+    pub fn last_review(&self) -> Option<&PerformanceReview> {
+        self.reviews
+            .last()
+    }
+}
+
+/// 해고되어 더 이상 월급과 업무를 받지 않음
+#[derive(Reflect, Component, Default, Debug)]
+#[reflect(Component)]
+pub struct Fired;
+
+#[derive(Event, Debug, Clone)]
+pub struct ReviewCompleted {
+    pub worker: Entity,
+    pub review: PerformanceReview,
+}
+
+pub struct CareerPlugin;
+
+impl Plugin for CareerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ReviewCompleted>()
+            .add_systems(
+                FixedUpdate,
+                (add_careers, count_conversations, quarterly_review)
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 인사 기록이 없는 직장인에게 직급, 직군, 인사 기록을 줌 (새로 생성되거나 예전 저장 파일에서 불러온 경우)
+fn add_careers(
+    mut commands: Commands,
+    q_workers: Query<(Entity, &Worker, Has<Rank>), Without<CareerRecord>>,
+) {
+    for (entity, worker, has_rank) in q_workers.iter() {
+        let mut entity = commands.entity(entity);
+        entity.insert((JobFamily::from_role(&worker.role), CareerRecord::default()));
+        // 배치도에서 정한 직급이 없으면 사원
+        if !has_rank {
+            entity.insert(Rank::default());
+        }
+    }
+}
+
+// 대화를 마치면 플레이어와 상대 모두 관계 점수를 얻음
+fn count_conversations(
+    mut dialogue_ended: EventReader<DialogueEnded>,
+    q_player: Query<Entity, With<Player>>,
+    mut q_records: Query<&mut CareerRecord>,
+) {
+    for event in dialogue_ended.read() {
+        for entity in q_player
+            .iter()
+            .chain(std::iter::once(event.partner))
+        {
+            if let Ok(mut record) = q_records.get_mut(entity) {
+                record.conversations += 1;
+            }
+        }
+    }
+}
+
+/// 분기가 시작되는 날 (1월, 4월, 7월, 10월 1일)
+fn is_quarter_start(event: &DayStarted) -> bool {
+    event.date.day == 1 && (event.date.month - 1).is_multiple_of(3)
+}
+
+// 분기마다 업무 성과와 평판으로 평가해서 연봉을 조정하고 승진, 강등, 해고를 결정
+fn quarterly_review(
+    mut commands: Commands,
+    mut day_started: EventReader<DayStarted>,
+    mut q_workers: Query<(Entity, &Name, &mut Worker, &mut Rank, &mut CareerRecord, &Performance)>,
+    q_relationships: Query<(Entity, &Relationships)>,
+    mut reviewed: EventWriter<ReviewCompleted>,
+) {
+    for event in day_started.read() {
+        if !is_quarter_start(event) {
+            continue;
+        }
+
+        for (entity, name, mut worker, mut rank, mut record, performance) in q_workers.iter_mut() {
+            let completed = performance
+                .completed
+                .saturating_sub(record.completed_at_last_review);
+            let missed = performance
+                .missed
+                .saturating_sub(record.missed_at_last_review);
+            let conversations = record.conversations;
+            record.completed_at_last_review = performance.completed;
+            record.missed_at_last_review = performance.missed;
+            record.conversations = 0;
+
+            let peer_reputation = reputation(entity, q_relationships.iter());
+            let Some(grade) = Grade::from_quarter(completed, missed, conversations, peer_reputation) else {
+                continue;
+            };
+            let mut raise = grade.raise();
+            record.promotion_points += grade.promotion_points();
+            record.warning_points += grade.warning_points();
+
+            let outcome = if record.promotion_points >= PROMOTION_POINTS {
+                match rank.next() {
+                    Some(next) => CareerOutcome::Promoted(next),
+                    None => CareerOutcome::Stayed,
+                }
+            } else if record.warning_points >= WARNING_POINTS {
+                match rank.previous() {
+                    Some(previous) => CareerOutcome::Demoted(previous),
+                    None => CareerOutcome::Fired,
+                }
+            } else {
+                CareerOutcome::Stayed
+            };
+            match outcome {
+                CareerOutcome::Promoted(next) => {
+                    *rank = next;
+                    raise += PROMOTION_RAISE;
+                    record.promotion_points = 0;
+                    record.warning_points = 0;
+                }
+                CareerOutcome::Demoted(previous) => {
+                    *rank = previous;
+                    raise -= DEMOTION_CUT;
+                    record.promotion_points = 0;
+                    record.warning_points = 0;
+                }
+                CareerOutcome::Fired => {
+                    commands
+                        .entity(entity)
+                        .remove::<(Worker, TaskList)>()
+                        .insert(Fired);
+                }
+                CareerOutcome::Stayed => {}
+            }
+
+            // 연봉은 만원 단위로 맞춤
+            match worker
+                .salary
+                .amount
+                .mul_rate(1.0 + raise)
+                .and_then(|salary| salary.round_to(Money::man_won(1)))
+            {
+                Ok(salary) => worker.salary.amount = salary,
+                Err(error) => error!("{} 연봉을 조정할 수 없습니다: {}", name.as_str(), error),
+            }
+
+            let review = PerformanceReview {
+                day: event.day,
+                completed,
+                missed,
+                conversations,
+                grade,
+                outcome,
+            };
+            info!(
+                "{} 인사 평가 {}: {} ({:?}), 연봉 {}",
+                name.as_str(),
+                event.date,
+                grade,
+                outcome,
+                worker
+                    .salary
+                    .in_man_won()
+            );
+            record
+                .reviews
+                .push(review.clone());
+            reviewed.send(ReviewCompleted {
+                worker: entity,
+                review,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{GameDate, Weekday};
+    use crate::components::Salary;
+
+    #[test]
+    fn grade_boundaries() {
+        assert_eq!(Grade::from_quarter(0, 0, 5, 1.0), None);
+        // 동료 관계가 없으면 업무 성과만으로 최대 0.8
+        assert_eq!(Grade::from_quarter(10, 0, 0, 0.0), Some(Grade::A));
+        assert_eq!(Grade::from_quarter(7, 3, 0, 0.0), Some(Grade::B));
+        assert_eq!(Grade::from_quarter(3, 2, 0, 0.0), Some(Grade::C));
+        assert_eq!(Grade::from_quarter(1, 3, 0, 0.0), Some(Grade::D));
+        assert_eq!(Grade::from_quarter(10, 0, 10, 1.0), Some(Grade::S));
+        assert_eq!(Grade::from_quarter(1, 1, 10, 1.0), Some(Grade::B));
+        // 대화 수와 평판은 만점을 넘지 않음
+        assert_eq!(Grade::from_quarter(1, 1, 100, 5.0), Some(Grade::B));
+        assert_eq!(Grade::from_quarter(1, 1, 0, 5.0), Some(Grade::C));
+    }
+
+    fn review_app() -> App {
+        let mut app = App::new();
+        app.add_event::<DayStarted>()
+            .add_event::<ReviewCompleted>()
+            .add_systems(Update, quarterly_review);
+        app
+    }
+
+    fn spawn_worker(app: &mut App, salary: i64, rank: Rank, record: CareerRecord, completed: u32, missed: u32) -> Entity {
+        app.world
+            .spawn((
+                Name::new("김대리"),
+                Worker {
+                    salary: Salary {
+                        amount: Money::man_won(salary),
+                    },
+                    role: "개발".to_string(),
+                },
+                rank,
+                record,
+                Performance { completed, missed },
+                TaskList::default(),
+            ))
+            .id()
+    }
+
+    fn start_day(app: &mut App, month: u32, day: u32) {
+        app.world
+            .send_event(DayStarted {
+                day: 0,
+                date: GameDate { year: 2024, month, day },
+                weekday: Weekday::Monday,
+            });
+        app.update();
+    }
+
+    fn salary(app: &App, entity: Entity) -> Money {
+        app.world
+            .get::<Worker>(entity)
+            .unwrap()
+            .salary
+            .amount
+    }
+
+    #[test]
+    fn review_only_on_quarter_start() {
+        let mut app = review_app();
+        let worker = spawn_worker(&mut app, 3000, Rank::Staff, CareerRecord::default(), 10, 0);
+        start_day(&mut app, 4, 2);
+        start_day(&mut app, 5, 1);
+        assert!(app
+            .world
+            .get::<CareerRecord>(worker)
+            .unwrap()
+            .reviews
+            .is_empty());
+        start_day(&mut app, 4, 1);
+        assert_eq!(
+            app.world
+                .get::<CareerRecord>(worker)
+                .unwrap()
+                .reviews
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn promotion_raises_salary_and_resets_points() {
+        let mut app = review_app();
+        let record = CareerRecord {
+            promotion_points: PROMOTION_POINTS - 1,
+            warning_points: 1,
+            ..default()
+        };
+        // A 등급 5% 인상과 승진 10% 인상
+        let worker = spawn_worker(&mut app, 3000, Rank::Staff, record, 10, 0);
+        start_day(&mut app, 1, 1);
+
+        assert_eq!(*app.world.get::<Rank>(worker).unwrap(), Rank::AssistantManager);
+        assert_eq!(salary(&app, worker), Money::man_won(3450));
+        let record = app
+            .world
+            .get::<CareerRecord>(worker)
+            .unwrap();
+        assert_eq!((record.promotion_points, record.warning_points), (0, 0));
+        assert_eq!(
+            record
+                .last_review()
+                .map(|review| (review.grade, review.outcome)),
+            Some((Grade::A, CareerOutcome::Promoted(Rank::AssistantManager)))
+        );
+    }
+
+    #[test]
+    fn top_rank_stays_when_promoted() {
+        let mut app = review_app();
+        let record = CareerRecord {
+            promotion_points: PROMOTION_POINTS - 1,
+            ..default()
+        };
+        let worker = spawn_worker(&mut app, 10000, Rank::Director, record, 10, 0);
+        start_day(&mut app, 1, 1);
+
+        assert_eq!(*app.world.get::<Rank>(worker).unwrap(), Rank::Director);
+        assert_eq!(salary(&app, worker), Money::man_won(10500));
+    }
+
+    #[test]
+    fn salary_is_rounded_to_man_won() {
+        let mut app = review_app();
+        // B 등급 2% 인상: 3333만원 * 1.02 = 3399.66만원
+        let worker = spawn_worker(&mut app, 3333, Rank::Staff, CareerRecord::default(), 7, 3);
+        start_day(&mut app, 7, 1);
+        assert_eq!(salary(&app, worker), Money::man_won(3400));
+    }
+
+    #[test]
+    fn warnings_demote_and_cut_salary() {
+        let mut app = review_app();
+        let record = CareerRecord {
+            warning_points: WARNING_POINTS - 2,
+            ..default()
+        };
+        // D 등급 3% 삭감과 강등 5% 삭감
+        let worker = spawn_worker(&mut app, 5000, Rank::Manager, record, 0, 10);
+        start_day(&mut app, 10, 1);
+
+        assert_eq!(*app.world.get::<Rank>(worker).unwrap(), Rank::AssistantManager);
+        assert_eq!(salary(&app, worker), Money::man_won(4600));
+        assert!(app
+            .world
+            .get::<Fired>(worker)
+            .is_none());
+    }
+
+    #[test]
+    fn staff_with_warnings_is_fired_and_no_longer_reviewed() {
+        let mut app = review_app();
+        let record = CareerRecord {
+            warning_points: WARNING_POINTS - 2,
+            ..default()
+        };
+        let worker = spawn_worker(&mut app, 3000, Rank::Staff, record, 0, 10);
+        start_day(&mut app, 10, 1);
+
+        let entity = app
+            .world
+            .entity(worker);
+        assert!(entity.contains::<Fired>());
+        assert!(!entity.contains::<Worker>());
+        assert!(!entity.contains::<TaskList>());
+        assert_eq!(*entity.get::<Rank>().unwrap(), Rank::Staff);
+
+        // 해고된 사람은 다음 분기에 평가받지 않음
+        app.world
+            .get_mut::<Performance>(worker)
+            .unwrap()
+            .completed = 10;
+        start_day(&mut app, 1, 1);
+        let record = app
+            .world
+            .get::<CareerRecord>(worker)
+            .unwrap();
+        assert_eq!(record.reviews.len(), 1);
+        assert_eq!(
+            record
+                .last_review()
+                .map(|review| review.outcome),
+            Some(CareerOutcome::Fired)
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::actions::{Action, ActionInput};
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+const SECONDS_PER_HOUR: f64 = 60.0 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: f64 = 24.0 * SECONDS_PER_HOUR;
+
+/// 달력이 시작되는 해. 1월 1일이 월요일입니다.
+const START_YEAR: u32 = 2024;
+
+/// 게임 속 달력과 시계
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct GameClock {
+    /// START_YEAR년 1월 1일 0시부터 지난 게임 시간 (초)
+    pub elapsed_seconds: f64,
+    /// 현실 1초 동안 흐르는 게임 시간 (초)
+    pub time_scale: f32,
+    pub paused: bool,
+    pub work_start_hour: u32,
+    pub work_end_hour: u32,
+    /// 월급날 (매달 며칠)
+    pub payday: u32,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        GameClock {
+            elapsed_seconds: 8.0 * SECONDS_PER_HOUR,
+            time_scale: 60.0,
+            paused: false,
+            work_start_hour: 9,
+            work_end_hour: 18,
+            payday: 25,
+        }
+    }
+}
+
+impl GameClock {
+    /// 게임 시작일로부터 지난 날 수
+    pub fn day(&self) -> u32 {
+        (self.elapsed_seconds / SECONDS_PER_DAY) as u32
+    }
+
+    pub fn hour(&self) -> u32 {
+        ((self.elapsed_seconds % SECONDS_PER_DAY) / SECONDS_PER_HOUR) as u32
+    }
+
+    pub fn minute(&self) -> u32 {
+        ((self.elapsed_seconds % SECONDS_PER_HOUR) / SECONDS_PER_MINUTE) as u32
+    }
+
+    /// 하루 중 지난 시간 (시간 단위, 예: 9.5 = 09:30)
+    pub fn hour_of_day(&self) -> f32 {
+        ((self.elapsed_seconds % SECONDS_PER_DAY) / SECONDS_PER_HOUR) as f32
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_day(self.day())
+    }
+
+    pub fn is_weekend(&self) -> bool {
+        self.weekday()
+            .is_weekend()
+    }
+
+    pub fn date(&self) -> GameDate {
+        GameDate::from_day(self.day())
+    }
+
+    /// 이번 프레임에 흐른 게임 시간 (초)
+    pub fn scaled_delta_seconds(&self, time: &Time) -> f64 {
+        time.delta_seconds_f64() * self.time_scale as f64
+    }
+
+    /// 이번 프레임에 흐른 게임 시간 (시간)
+    pub fn scaled_delta_hours(&self, time: &Time) -> f32 {
+        (self.scaled_delta_seconds(time) / SECONDS_PER_HOUR) as f32
+    }
+
+    /// 평일 근무 시간인지
+    pub fn is_work_hours(&self) -> bool {
+        !self.is_weekend()
+            && self.hour() >= self.work_start_hour
+            && self.hour() < self.work_end_hour
+    }
+
+    /// 월급날인지. 그 달에 월급날이 없으면 (예: 2월 30일) 그 달의 마지막 날에 줌
+    pub fn is_payday(&self, date: GameDate) -> bool {
+        date.day
+            == self
+                .payday
+                .clamp(1, date.days_in_month())
+    }
+}
+
+impl fmt::Display for GameClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) {:02}:{:02}",
+            self.date(),
+            self.weekday(),
+            self.hour(),
+            self.minute()
+        )
+    }
+}
+
+#[derive(Reflect, Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// 게임 시작일로부터 `day`일 지난 날의 요일
+    pub fn from_day(day: u32) -> Self {
+        Self::ALL[(day % 7) as usize]
+    }
+
+    pub fn is_weekend(&self) -> bool {
+        matches!(self, Weekday::Saturday | Weekday::Sunday)
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Weekday::Monday => "월",
+            Weekday::Tuesday => "화",
+            Weekday::Wednesday => "수",
+            Weekday::Thursday => "목",
+            Weekday::Friday => "금",
+            Weekday::Saturday => "토",
+            Weekday::Sunday => "일",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Reflect, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GameDate {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl GameDate {
+    /// 게임 시작일로부터 `day`일 지난 날짜
+    pub fn from_day(mut day: u32) -> Self {
+        let mut year = START_YEAR;
+        while day >= days_in_year(year) {
+            day -= days_in_year(year);
+            year += 1;
+        }
+        let mut month = 1;
+        while day >= days_in_month(year, month) {
+            day -= days_in_month(year, month);
+            month += 1;
+        }
+        GameDate {
+            year,
+            month,
+            day: day + 1,
+        }
+    }
+
+    pub fn days_in_month(&self) -> u32 {
+        days_in_month(self.year, self.month)
+    }
+}
+
+impl fmt::Display for GameDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}년 {}월 {}일", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+fn days_in_year(year: u32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 자정이 지나 새 날이 시작됨
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DayStarted {
+    pub day: u32,
+    pub date: GameDate,
+    pub weekday: Weekday,
+}
+
+/// 정각이 됨
+#[derive(Event, Debug, Clone, Copy)]
+pub struct HourStarted {
+    pub day: u32,
+    pub hour: u32,
+}
+
+/// 평일 퇴근 시간이 됨
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WorkHoursEnded {
+    pub day: u32,
+}
+
+/// 월급날이 시작됨
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Payday {
+    pub date: GameDate,
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClockSet;
+
+pub struct ClockPlugin;
+
+impl Plugin for ClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameClock>()
+            .add_event::<DayStarted>()
+            .add_event::<HourStarted>()
+            .add_event::<WorkHoursEnded>()
+            .add_event::<Payday>()
+            .add_systems(
+                FixedUpdate,
+                (clock_input, tick_clock.run_if(clock_running))
+                    .chain()
+                    .in_set(ClockSet),
+            );
+    }
+}
+
+/// 시계가 멈춰 있지 않을 때만 월드를 갱신하기 위한 실행 조건
+pub fn clock_running(clock: Res<GameClock>) -> bool {
+    !clock.paused
+}
+
+// 일시정지 (기본 P), 시간 배율 절반 / 두 배 (기본 [ / ])
+fn clock_input(actions: ActionInput, mut clock: ResMut<GameClock>) {
+    if actions.just_pressed(Action::Pause) {
+        clock.paused = !clock.paused;
+    }
+    if actions.just_pressed(Action::SlowDown) {
+        clock.time_scale = (clock.time_scale / 2.0).max(1.0);
+    }
+    if actions.just_pressed(Action::SpeedUp) {
+        clock.time_scale = (clock.time_scale * 2.0).min(86400.0);
+    }
+}
+
+fn tick_clock(
+    time: Res<Time>,
+    mut clock: ResMut<GameClock>,
+    mut day_started: EventWriter<DayStarted>,
+    mut hour_started: EventWriter<HourStarted>,
+    mut work_hours_ended: EventWriter<WorkHoursEnded>,
+    mut payday: EventWriter<Payday>,
+) {
+    let previous = clock.elapsed_seconds;
+    clock.elapsed_seconds += clock.scaled_delta_seconds(&time);
+    let current = clock.elapsed_seconds;
+
+    let first_hour = (previous / SECONDS_PER_HOUR) as u32 + 1;
+    let last_hour = (current / SECONDS_PER_HOUR) as u32;
+    for hour in first_hour..=last_hour {
+        hour_started.send(HourStarted {
+            day: hour / 24,
+            hour: hour % 24,
+        });
+    }
+
+    // 한 프레임에 여러 날이 지나갈 수 있으므로 지나간 날마다 확인
+    let first_day = (previous / SECONDS_PER_DAY) as u32;
+    let last_day = (current / SECONDS_PER_DAY) as u32;
+    for day in first_day..=last_day {
+        let day_start = day as f64 * SECONDS_PER_DAY;
+        let weekday = Weekday::from_day(day);
+        let date = GameDate::from_day(day);
+
+        if previous < day_start && day_start <= current {
+            day_started.send(DayStarted { day, date, weekday });
+            if clock.is_payday(date) {
+                payday.send(Payday { date });
+            }
+        }
+
+        let work_end = day_start + clock.work_end_hour as f64 * SECONDS_PER_HOUR;
+        if !weekday.is_weekend() && previous < work_end && work_end <= current {
+            work_hours_ended.send(WorkHoursEnded { day });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u32, month: u32, day: u32) -> GameDate {
+        GameDate { year, month, day }
+    }
+
+    #[test]
+    fn from_day_handles_leap_years_and_month_rollover() {
+        assert_eq!(GameDate::from_day(0), date(2024, 1, 1));
+        assert_eq!(GameDate::from_day(30), date(2024, 1, 31));
+        assert_eq!(GameDate::from_day(31), date(2024, 2, 1));
+        // 2024년은 윤년
+        assert_eq!(GameDate::from_day(59), date(2024, 2, 29));
+        assert_eq!(GameDate::from_day(60), date(2024, 3, 1));
+        assert_eq!(GameDate::from_day(365), date(2024, 12, 31));
+        assert_eq!(GameDate::from_day(366), date(2025, 1, 1));
+        // 2025년은 평년
+        assert_eq!(GameDate::from_day(366 + 58), date(2025, 2, 28));
+        assert_eq!(GameDate::from_day(366 + 59), date(2025, 3, 1));
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2025));
+        assert!(!is_leap_year(2100));
+        assert!(is_leap_year(2000));
+    }
+
+    #[test]
+    fn payday_is_clamped_to_last_day_of_month() {
+        let clock = GameClock {
+            payday: 31,
+            ..default()
+        };
+        assert!(clock.is_payday(date(2024, 1, 31)));
+        assert!(clock.is_payday(date(2024, 2, 29)));
+        assert!(!clock.is_payday(date(2024, 2, 28)));
+        assert!(clock.is_payday(date(2025, 2, 28)));
+        assert!(clock.is_payday(date(2024, 4, 30)));
+
+        let clock = GameClock {
+            payday: 30,
+            ..default()
+        };
+        assert!(clock.is_payday(date(2024, 2, 29)));
+        assert!(!clock.is_payday(date(2024, 3, 31)));
+        assert!(clock.is_payday(date(2024, 3, 30)));
+    }
+
+    #[test]
+    fn payday_comes_once_every_month() {
+        for payday in [1, 25, 29, 30, 31] {
+            let clock = GameClock { payday, ..default() };
+            let paydays: Vec<GameDate> = (0..366 + 365)
+                .map(GameDate::from_day)
+                .filter(|date| clock.is_payday(*date))
+                .collect();
+            assert_eq!(paydays.len(), 24, "payday {}", payday);
+            for (index, date) in paydays
+                .iter()
+                .enumerate()
+            {
+                assert_eq!(date.month, index as u32 % 12 + 1);
+            }
+        }
+    }
+}
@@ -1,6 +1,24 @@
 use bevy::prelude::*;
 
+use crate::interaction::InteractionAction;
+use crate::money::Money;
+use crate::needs::NEED_MAX;
+
+/// 지치지 않은 사람의 이동 속도
+pub const BASE_SPEED: f32 = 200.0;
+/// 사람의 모양. 메시와 충돌 판정에 함께 씁니다.
+pub const PERSON_SHAPE: Circle = Circle { radius: 20.0 };
+/// 책상의 모양 (50 x 100)
+pub const DESK_SHAPE: Rectangle = Rectangle {
+    half_size: Vec2::new(25.0, 50.0),
+};
+/// 프린터, 커피 머신 같은 물건의 모양 (30 x 30)
+pub const OBJECT_SHAPE: Rectangle = Rectangle {
+    half_size: Vec2::new(15.0, 15.0),
+};
+
 #[derive(Reflect, Component)]
+#[reflect(Component)]
 pub struct Person {
     pub speed: f32,
     pub hp: i32,
@@ -11,7 +29,7 @@ pub struct Person {
 impl Default for Person {
     fn default() -> Self {
         Person {
-            speed: 200.0,
+            speed: BASE_SPEED,
             hp: 100,
             san: 100,
             is_dead: false,
@@ -19,77 +37,74 @@ impl Default for Person {
     }
 }
 
-#[derive(Reflect, Debug, Clone, Eq, PartialEq, Hash)]
-pub enum InteractionType {
-    Invalid,
-    Work,
-    Damage,
-    SalaryMan,
+impl Person {
+    /// 체력을 바꿈. 욕구와 같이 0 ~ 100을 벗어나지 않습니다.
+    pub fn change_hp(&mut self, amount: i32) {
+        self.hp = clamp_stat(self.hp, amount);
+    }
+
+    /// 정신력을 바꿈. 욕구와 같이 0 ~ 100을 벗어나지 않습니다.
+    pub fn change_san(&mut self, amount: i32) {
+        self.san = clamp_stat(self.san, amount);
+    }
 }
 
-#[derive(Reflect, Component)]
-pub struct InteractionTarget {
-    pub is_interactable: bool,
-    pub target: Entity,
-    pub target_transform: Transform,
-    pub interaction_type: InteractionType,
+fn clamp_stat(value: i32, amount: i32) -> i32 {
+    value
+        .saturating_add(amount)
+        .clamp(0, NEED_MAX as i32)
 }
 
 #[derive(Reflect, Component)]
+#[reflect(Component)]
 pub struct Worker {
     pub salary: Salary,
+    pub role: String,
 }
 
 impl Default for Worker {
     fn default() -> Self {
         Worker {
             salary: Salary {
-                amount: 1000.0,
-                currency: "KRW".to_string(),
+                amount: Money::man_won(1000),
             },
+            role: "사무직".to_string(),
         }
     }
 }
 
-#[derive(Reflect, Component)]
+/// 연봉
+#[derive(Reflect, Component, Default)]
 pub struct Salary {
-    pub amount: f32,
-    pub currency: String,
-}
-
-impl Default for Salary {
-    fn default() -> Self {
-        Salary {
-            amount: 0.0,
-            currency: "KRW".to_string(),
-        }
-    }
+    pub amount: Money,
 }
 
 impl Salary {
     pub fn in_man_won(&self) -> String {
-        format!("{}만원", self.amount)
+        self.amount
+            .to_man_won_string()
     }
 
     pub fn in_won(&self) -> String {
-        format!("{}원", self.amount * 10000.0)
+        self.amount
+            .to_won_string()
     }
 }
 
-#[derive(Component)]
+#[derive(Reflect, Component, Default)]
+#[reflect(Component)]
 pub struct Desk;
 
-#[derive(Component)]
-pub struct Interactable {
-    pub interaction_type: InteractionType,
-}
+/// 타일맵의 벽 한 칸
+#[derive(Reflect, Component, Default)]
+#[reflect(Component)]
+pub struct Wall;
 
-impl Default for Interactable {
-    fn default() -> Self {
-        Interactable {
-            interaction_type: InteractionType::Invalid,
-        }
-    }
+/// 플레이어가 다가가서 할 수 있는 행동들. 조건을 만족하는 첫 행동이 힌트에 표시됩니다.
+#[derive(Reflect, Component, Default)]
+#[reflect(Component)]
+pub struct Interactable {
+    pub actions: Vec<InteractionAction>,
 }
 
 /// 마우스로 클릭 가능한 오브젝트를 나타내며, 마우스 오버 시 MouseHoverHint를 표시합니다.
@@ -110,6 +125,7 @@ impl Default for MouseSelectable {
 
 #[derive(Component)]
 pub struct CurrentHovered {
+    pub entity: Option<Entity>,
     pub selectable: Option<MouseSelectable>,
 }
 
@@ -130,3 +146,8 @@ impl Default for MouseInput {
 
 #[derive(Reflect, Component)]
 pub struct MouseHoverHint;
+
+/// 게임을 플레이한 시간 (초). 저장 파일에 함께 기록됩니다.
+#[derive(Reflect, Resource, Default)]
+#[reflect(Resource)]
+pub struct PlayTime(pub f32);
@@ -0,0 +1,568 @@
+use std::collections::HashMap;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::career::{JobFamily, Rank};
+use crate::components::{Person, Worker};
+use crate::gui::components::ChoiceUI;
+use crate::gui::{ChoiceDismissed, ChoiceSelected};
+use crate::needs::{NeedsRestore, RestoreNeeds};
+use crate::player::Player;
+use crate::relationships::{AdjustRelationship, RelationshipKind, Relationships};
+use crate::rumors::{RumorTopic, StartRumor};
+use crate::tasks::HelpWithTask;
+
+const COWORKER_DIALOGUE_PATH: &str = "dialogues/coworker.dialogue.ron";
+/// 선택할 수 있는 선택지가 없는 대사에 붙는 선택지
+const END_LABEL: &str = "대화 끝내기";
+
+/// 파일에서 읽어오는 대화 그래프
+///
+/// 대사의 `{name}`, `{salary}`, `{role}`, `{rank}`, `{job_family}`는
+/// 대화 상대의 이름, 연봉, 직무, 직급, 직군으로 바뀝니다.
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct DialogueGraph {
+    pub name: String,
+    /// 처음 보여줄 대사의 이름
+    pub start: String,
+    pub nodes: HashMap<String, DialogueNode>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueNode {
+    pub text: String,
+    #[serde(default)]
+    pub choices: Vec<DialogueChoice>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueChoice {
+    pub label: String,
+    /// 다음 대사의 이름. 없으면 대화가 끝납니다.
+    #[serde(default)]
+    pub next: Option<String>,
+    /// 모두 만족해야 선택지가 보입니다.
+    #[serde(default)]
+    pub conditions: Vec<DialogueCondition>,
+    #[serde(default)]
+    pub effects: Vec<DialogueEffect>,
+}
+
+/// 조건과 효과가 가리키는 사람. 관계 조건과 효과는 이 사람이 대화의 다른 쪽을 생각하는 수치입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DialogueSubject {
+    Player,
+    Partner,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum DialogueCondition {
+    HpAtLeast(DialogueSubject, i32),
+    SanAtLeast(DialogueSubject, i32),
+    SanBelow(DialogueSubject, i32),
+    /// 연봉 (만원)
+    SalaryAtLeast(DialogueSubject, i64),
+    RoleIs(DialogueSubject, String),
+    /// 직급이 이 이상 (사원, 대리, 과장...)
+    RankAtLeast(DialogueSubject, Rank),
+    /// 상대에 대한 호감, 신뢰, 경쟁심이 이 이상
+    RelationshipAtLeast(DialogueSubject, RelationshipKind, i32),
+    RelationshipBelow(DialogueSubject, RelationshipKind, i32),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum DialogueEffect {
+    ChangeHp(DialogueSubject, i32),
+    ChangeSan(DialogueSubject, i32),
+    /// 커피, 점심처럼 욕구를 채움
+    Restore(DialogueSubject, NeedsRestore),
+    ChangeRelationship(DialogueSubject, RelationshipKind, i32),
+    /// 상대의 지금 업무를 게임 시간만큼 도와줌
+    HelpWithTask(DialogueSubject, f32),
+    /// 이 사람에 대한 소문이 남. 대화의 다른 쪽이 처음 소문을 냅니다.
+    SpreadRumor(DialogueSubject, RumorTopic),
+}
+
+/// 조건을 확인할 때 보는 사람의 상태
+#[derive(Clone, Copy)]
+pub struct DialogueActor<'a> {
+    pub entity: Entity,
+    pub person: &'a Person,
+    pub worker: Option<&'a Worker>,
+    pub rank: Option<&'a Rank>,
+    pub job_family: Option<&'a JobFamily>,
+    pub relationships: Option<&'a Relationships>,
+}
+
+impl DialogueActor<'_> {
+    /// 이 사람이 `other`를 생각하는 수치
+    fn relationship(&self, other: &DialogueActor, kind: RelationshipKind) -> i32 {
+        self.relationships
+            .map_or(0, |relationships| relationships.value(other.entity, kind))
+    }
+}
+
+impl DialogueCondition {
+    pub fn is_met(&self, player: DialogueActor, partner: DialogueActor) -> bool {
+        let actor = |subject: &DialogueSubject| match subject {
+            DialogueSubject::Player => player,
+            DialogueSubject::Partner => partner,
+        };
+        let other = |subject: &DialogueSubject| match subject {
+            DialogueSubject::Player => partner,
+            DialogueSubject::Partner => player,
+        };
+        match self {
+            DialogueCondition::HpAtLeast(subject, hp) => actor(subject).person.hp >= *hp,
+            DialogueCondition::SanAtLeast(subject, san) => actor(subject).person.san >= *san,
+            DialogueCondition::SanBelow(subject, san) => actor(subject).person.san < *san,
+            DialogueCondition::SalaryAtLeast(subject, man_won) => actor(subject)
+                .worker
+                .is_some_and(|worker| worker.salary.amount.amount >= man_won * 10_000),
+            DialogueCondition::RoleIs(subject, role) => actor(subject)
+                .worker
+                .is_some_and(|worker| worker.role == *role),
+            DialogueCondition::RankAtLeast(subject, rank) => actor(subject)
+                .rank
+                .is_some_and(|actor_rank| actor_rank >= rank),
+            DialogueCondition::RelationshipAtLeast(subject, kind, value) => {
+                actor(subject).relationship(&other(subject), *kind) >= *value
+            }
+            DialogueCondition::RelationshipBelow(subject, kind, value) => {
+                actor(subject).relationship(&other(subject), *kind) < *value
+            }
+        }
+    }
+}
+
+impl DialogueEffect {
+    pub fn subject(&self) -> DialogueSubject {
+        match self {
+            DialogueEffect::ChangeHp(subject, _)
+            | DialogueEffect::ChangeSan(subject, _)
+            | DialogueEffect::Restore(subject, _)
+            | DialogueEffect::ChangeRelationship(subject, _, _)
+            | DialogueEffect::HelpWithTask(subject, _)
+            | DialogueEffect::SpreadRumor(subject, _) => *subject,
+        }
+    }
+}
+
+impl DialogueGraph {
+    /// 다음 대사로 이어지는 이름이 모두 그래프 안에 있는지 확인
+    fn validate(&self) -> Result<(), DialogueLoaderError> {
+        let missing = std::iter::once(&self.start)
+            .chain(
+                self.nodes
+                    .values()
+                    .flat_map(|node| node.choices.iter())
+                    .filter_map(|choice| choice.next.as_ref()),
+            )
+            .find(|next| !self.nodes.contains_key(*next));
+        match missing {
+            Some(next) => Err(DialogueLoaderError::MissingNode(next.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 대사의 자리표시자를 대화 상대의 정보로 채움
+pub fn fill_placeholders(text: &str, name: &str, actor: DialogueActor) -> String {
+    let mut text = text.replace("{name}", name);
+    if let Some(worker) = actor.worker {
+        text = text
+            .replace("{salary}", &worker.salary.in_man_won())
+            .replace("{role}", &worker.role);
+    }
+    if let Some(rank) = actor.rank {
+        text = text.replace("{rank}", rank.name());
+    }
+    if let Some(job_family) = actor.job_family {
+        text = text.replace("{job_family}", job_family.name());
+    }
+    text
+}
+
+/// 게임에서 쓰는 대화 그래프 핸들
+#[derive(Resource, Default)]
+pub struct DialogueLibrary {
+    pub coworker: Handle<DialogueGraph>,
+}
+
+/// 진행 중인 대화
+#[derive(Resource, Default, Debug)]
+pub struct DialogueRunner {
+    pub graph: Handle<DialogueGraph>,
+    pub partner: Option<Entity>,
+    /// 지금 보여주는 대사의 이름
+    pub node: Option<String>,
+    /// ChoiceUI에 보이는 선택지들의 원래 번호
+    pub shown_choices: Vec<usize>,
+}
+
+impl DialogueRunner {
+    pub fn is_active(&self) -> bool {
+        self.node
+            .is_some()
+    }
+
+    fn end(&mut self) {
+        self.partner = None;
+        self.node = None;
+        self.shown_choices
+            .clear();
+    }
+}
+
+/// 동료와 대화를 시작
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StartDialogue {
+    pub partner: Entity,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DialogueEnded {
+    pub partner: Entity,
+}
+
+#[derive(Default)]
+pub struct DialogueGraphLoader;
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum DialogueLoaderError {
+    #[error("Could not load dialogue: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse RON dialogue: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Dialogue node {0:?} does not exist")]
+    MissingNode(String),
+}
+
+impl AssetLoader for DialogueGraphLoader {
+    type Asset = DialogueGraph;
+    type Settings = ();
+    type Error = DialogueLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await?;
+            let graph = ron::de::from_bytes::<DialogueGraph>(&bytes)?;
+            graph.validate()?;
+            Ok(graph)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["dialogue.ron"]
+    }
+}
+
+pub struct DialoguePlugin;
+
+impl Plugin for DialoguePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<DialogueGraph>()
+            .init_asset_loader::<DialogueGraphLoader>()
+            .init_resource::<DialogueLibrary>()
+            .init_resource::<DialogueRunner>()
+            .add_event::<StartDialogue>()
+            .add_event::<ChoiceSelected>()
+            .add_event::<ChoiceDismissed>()
+            .add_event::<DialogueEnded>()
+            .add_systems(Startup, load_dialogues)
+            .add_systems(
+                FixedUpdate,
+                (
+                    start_dialogue,
+                    dismiss_dialogue,
+                    select_dialogue_choice,
+                    refresh_dialogue,
+                )
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+fn load_dialogues(asset_server: Res<AssetServer>, mut library: ResMut<DialogueLibrary>) {
+    library.coworker = asset_server.load(COWORKER_DIALOGUE_PATH);
+}
+
+fn start_dialogue(
+    mut events: EventReader<StartDialogue>,
+    library: Res<DialogueLibrary>,
+    graphs: Res<Assets<DialogueGraph>>,
+    mut runner: ResMut<DialogueRunner>,
+) {
+    for event in events.read() {
+        let Some(graph) = graphs.get(&library.coworker) else {
+            warn!("Dialogue {} is not loaded yet", COWORKER_DIALOGUE_PATH);
+            continue;
+        };
+        runner.graph = library
+            .coworker
+            .clone();
+        runner.partner = Some(event.partner);
+        runner.node = Some(
+            graph
+                .start
+                .clone(),
+        );
+    }
+}
+
+// ChoiceUI를 닫으면 대화를 끝냄
+fn dismiss_dialogue(
+    mut events: EventReader<ChoiceDismissed>,
+    mut runner: ResMut<DialogueRunner>,
+    mut ended: EventWriter<DialogueEnded>,
+) {
+    for _ in events.read() {
+        if let Some(partner) = runner.partner {
+            runner.end();
+            ended.send(DialogueEnded { partner });
+        }
+    }
+}
+
+/// 선택지 효과가 보내는 이벤트
+#[derive(SystemParam)]
+struct DialogueEffectEvents<'w> {
+    restore: EventWriter<'w, RestoreNeeds>,
+    adjust: EventWriter<'w, AdjustRelationship>,
+    help: EventWriter<'w, HelpWithTask>,
+    rumor: EventWriter<'w, StartRumor>,
+}
+
+// 고른 선택지의 효과를 적용하고 다음 대사로 넘어감
+fn select_dialogue_choice(
+    mut events: EventReader<ChoiceSelected>,
+    graphs: Res<Assets<DialogueGraph>>,
+    mut runner: ResMut<DialogueRunner>,
+    mut q_people: Query<&mut Person>,
+    q_player: Query<Entity, With<Player>>,
+    mut ended: EventWriter<DialogueEnded>,
+    mut effect_events: DialogueEffectEvents,
+) {
+    for event in events.read() {
+        let (Some(partner), Some(node)) = (runner.partner, runner.node.as_ref()) else {
+            continue;
+        };
+        // 그래프가 다시 로드되어 대사가 사라졌으면 대화를 끝냄
+        let Some(node) = graphs
+            .get(&runner.graph)
+            .and_then(|graph| graph.nodes.get(node))
+        else {
+            runner.end();
+            ended.send(DialogueEnded { partner });
+            continue;
+        };
+        // 선택지가 없는 대사에서는 "대화 끝내기"만 있음
+        if runner
+            .shown_choices
+            .is_empty()
+        {
+            if event.index == 0 {
+                runner.end();
+                ended.send(DialogueEnded { partner });
+            }
+            continue;
+        }
+
+        let Some(choice) = runner
+            .shown_choices
+            .get(event.index)
+            .and_then(|index| node.choices.get(*index))
+        else {
+            continue;
+        };
+
+        let Ok(player) = q_player.get_single() else {
+            continue;
+        };
+        for effect in choice
+            .effects
+            .iter()
+        {
+            let (entity, other) = match effect.subject() {
+                DialogueSubject::Player => (player, partner),
+                DialogueSubject::Partner => (partner, player),
+            };
+            match effect {
+                DialogueEffect::ChangeHp(_, amount) => {
+                    if let Ok(mut person) = q_people.get_mut(entity) {
+                        person.change_hp(*amount);
+                    }
+                }
+                DialogueEffect::ChangeSan(_, amount) => {
+                    if let Ok(mut person) = q_people.get_mut(entity) {
+                        person.change_san(*amount);
+                    }
+                }
+                DialogueEffect::Restore(_, source) => {
+                    effect_events
+                        .restore
+                        .send(RestoreNeeds {
+                            entity,
+                            source: *source,
+                        });
+                }
+                DialogueEffect::ChangeRelationship(_, kind, amount) => {
+                    effect_events
+                        .adjust
+                        .send(AdjustRelationship {
+                            entity,
+                            other,
+                            kind: *kind,
+                            amount: *amount,
+                        });
+                }
+                DialogueEffect::HelpWithTask(_, hours) => {
+                    effect_events
+                        .help
+                        .send(HelpWithTask {
+                            helper: entity,
+                            worker: other,
+                            hours: *hours,
+                        });
+                }
+                DialogueEffect::SpreadRumor(_, topic) => {
+                    effect_events
+                        .rumor
+                        .send(StartRumor {
+                            topic: *topic,
+                            subject: entity,
+                            source: other,
+                        });
+                }
+            }
+        }
+
+        match choice
+            .next
+            .clone()
+        {
+            Some(next) => runner.node = Some(next),
+            None => {
+                runner.end();
+                ended.send(DialogueEnded { partner });
+            }
+        }
+    }
+}
+
+/// 대화에 참여하는 사람의 상태
+#[derive(QueryData)]
+struct DialoguePerson {
+    entity: Entity,
+    person: &'static Person,
+    worker: Option<&'static Worker>,
+    rank: Option<&'static Rank>,
+    job_family: Option<&'static JobFamily>,
+    relationships: Option<&'static Relationships>,
+    name: &'static Name,
+    transform: &'static Transform,
+}
+
+impl DialoguePersonItem<'_> {
+    fn actor(&self) -> DialogueActor<'_> {
+        DialogueActor {
+            entity: self.entity,
+            person: self.person,
+            worker: self.worker,
+            rank: self.rank,
+            job_family: self.job_family,
+            relationships: self.relationships,
+        }
+    }
+}
+
+// 대사가 바뀌면 조건을 만족하는 선택지만 ChoiceUI에 보여줌
+fn refresh_dialogue(
+    graphs: Res<Assets<DialogueGraph>>,
+    mut runner: ResMut<DialogueRunner>,
+    q_people: Query<DialoguePerson>,
+    q_player: Query<Entity, With<Player>>,
+    mut choice_ui: Query<&mut ChoiceUI>,
+) {
+    if !runner.is_changed() {
+        return;
+    }
+
+    let node = runner
+        .node
+        .as_ref()
+        .and_then(|node| graphs.get(&runner.graph)?.nodes.get(node));
+    let partner = runner
+        .partner
+        .and_then(|partner| q_people.get(partner).ok());
+    let player = q_player
+        .get_single()
+        .ok()
+        .and_then(|player| q_people.get(player).ok());
+
+    let (Some(node), Some(partner), Some(player)) = (node, partner, player) else {
+        // 대화가 끝났거나 상대가 사라짐
+        runner
+            .bypass_change_detection()
+            .end();
+        for mut _choice in choice_ui.iter_mut() {
+            _choice.is_visible = false;
+        }
+        return;
+    };
+
+    let player_actor = player.actor();
+    let partner_actor = partner.actor();
+
+    let shown_choices: Vec<usize> = node
+        .choices
+        .iter()
+        .enumerate()
+        .filter(|(_, choice)| {
+            choice
+                .conditions
+                .iter()
+                .all(|condition| condition.is_met(player_actor, partner_actor))
+        })
+        .map(|(index, _)| index)
+        .collect();
+    let mut labels: Vec<String> = shown_choices
+        .iter()
+        .map(|index| {
+            node.choices[*index]
+                .label
+                .clone()
+        })
+        .collect();
+    if labels.is_empty() {
+        labels.push(END_LABEL.to_string());
+    }
+
+    for mut _choice in choice_ui.iter_mut() {
+        _choice.is_visible = true;
+        _choice.tranform = *partner.transform;
+        _choice.prompt = fill_placeholders(&node.text, partner.name.as_str(), partner_actor);
+        _choice.choices = labels.clone();
+        _choice.selected = 0;
+    }
+    runner
+        .bypass_change_detection()
+        .shown_choices = shown_choices;
+}
@@ -1,9 +1,47 @@
+use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
 
 pub mod components;
-use crate::components::MouseSelectable;
+use crate::actions::{Action, ActionInput, ActionMap, InputDevice, RebindAction, UiActionInput};
+use crate::components::{CurrentHovered, MouseSelectable};
+use crate::incidents::IncidentOccurred;
+use crate::interaction::InteractionTargets;
+use crate::money::{Money, MoneyError};
+use crate::payroll::PayslipHistory;
+use crate::player::Player;
+use crate::tasks::{TaskList, Working};
+use crate::tick::{TickInput, UiInputFocus};
 
-use components::{ChoiceItem, ChoiceUI, InteractionHintUI, PopUpUI, StatusHUD};
+use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
+use components::{
+    ChoiceItem, ChoicePrompt, ChoiceUI, IncidentPopUp, InteractionHintUI, InteractionWheelItem, PayslipUI, PopUpUI,
+    RebindingUI, StatusHUD, TaskProgressBar, TaskProgressFill,
+};
+
+const CHOICE_KEYS: [KeyCode; 8] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+];
+
+/// ChoiceUI에서 선택지를 고름 (클릭, 선택 키, 숫자 키)
+#[derive(Event, Debug, Clone)]
+pub struct ChoiceSelected {
+    pub ui: Entity,
+    pub index: usize,
+    pub label: String,
+}
+
+/// 닫기 키(기본 Esc)로 ChoiceUI를 닫음
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ChoiceDismissed {
+    pub ui: Entity,
+}
 
 #[derive(Resource)]
 pub struct MyFont(Handle<Font>);
@@ -18,15 +56,28 @@ pub fn add_gui(mut commands: Commands, font: Res<MyFont>) {
     add_text(&mut commands, &font);
     add_hud(&mut commands, &font);
     add_pop_up(&mut commands, &font);
+    add_incident_pop_up(&mut commands, &font);
     add_choice_ui(&mut commands, &font);
+    add_payslip_ui(&mut commands, &font);
+    add_rebinding_ui(&mut commands, &font);
+    add_interaction_wheel(&mut commands, &font);
+}
+
+/// 흔들리고 도는 팝업의 글자
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct PopUpText {
+    text: &'static mut Text,
+    transform: &'static mut Transform,
 }
 
 // 게임 오버 팝업을 업데이트
-pub fn update_pop_up(
-    mut query: Query<(&mut Text, &mut Transform), With<PopUpUI>>,
-    time: Res<Time>,
-) {
-    for (mut _text, mut _transform) in query.iter_mut() {
+pub fn update_pop_up(mut query: Query<PopUpText, (With<PopUpUI>, Without<IncidentPopUp>)>, time: Res<Time>) {
+    for PopUpTextItem {
+        text: mut _text,
+        transform: mut _transform,
+    } in query.iter_mut()
+    {
         let million = time.elapsed_seconds() % 8.0;
         if million < 4.0 {
             let scale = ((time.elapsed_seconds() * 30.0).sin() + 2.1) * 1.0;
@@ -47,15 +98,35 @@ pub fn update_pop_up(
 }
 
 pub fn update_choice_ui(
-    mut query: Query<&ChoiceUI>,
+    query: Query<&ChoiceUI>,
     mut choice_item_query: Query<
         (&mut Text, &mut Visibility, &mut Transform, &ChoiceItem),
-        With<ChoiceItem>,
+        Without<ChoicePrompt>,
     >,
+    mut prompt_query: Query<(&mut Text, &mut Visibility, &mut Transform), With<ChoicePrompt>>,
 ) {
-    for _choice in query.iter_mut() {
-        if _choice.is_visible == false {
-            return;
+    for _choice in query.iter() {
+        // 닫힌 선택지는 화면 밖으로 치우고 숨김
+        if !_choice.is_visible {
+            for (_, mut _prompt_visibility, _) in prompt_query.iter_mut() {
+                *_prompt_visibility = Visibility::Hidden;
+            }
+            for (_, mut _item_visibility, mut _transform, _) in choice_item_query.iter_mut() {
+                *_item_visibility = Visibility::Hidden;
+                _transform.translation = Transform::from_xyz(10000.0, 10000.0, 0.0).translation;
+            }
+            continue;
+        }
+
+        for (mut _text, mut _prompt_visibility, mut _transform) in prompt_query.iter_mut() {
+            _text.sections[0].value = _choice
+                .prompt
+                .clone();
+            *_prompt_visibility = Visibility::Visible;
+            _transform.translation = _choice
+                .tranform
+                .translation
+                + Vec3::new(80.0, 0.0, 10.0);
         }
 
         for (mut _text, mut _item_visibility, mut _transform, _choice_item) in
@@ -71,7 +142,19 @@ pub fn update_choice_ui(
                 continue;
             }
 
-            _text.sections[0].value = _choice.choices[_choice_item.index].clone();
+            _text.sections[0].value = format!(
+                "{}. {}",
+                _choice_item.index + 1,
+                _choice.choices[_choice_item.index]
+            );
+            // 현재 선택지를 강조
+            _text.sections[0]
+                .style
+                .color = if _choice_item.index == _choice.selected {
+                Color::YELLOW
+            } else {
+                Color::WHITE
+            };
             *_item_visibility = Visibility::Visible;
 
             _transform
@@ -93,6 +176,383 @@ pub fn update_choice_ui(
     }
 }
 
+// 이전/다음 선택지 키(기본 위/아래 화살표)로 이동하고 클릭, 선택 키, 숫자 키로 고름. 닫기 키로 닫음
+// 대화 진행을 재생할 수 있도록 시뮬레이션 틱에서 처리
+pub fn choice_input(
+    actions: ActionInput,
+    mouse_input: Res<TickInput<MouseButton>>,
+    mut query: Query<(Entity, &mut ChoiceUI)>,
+    q_current_hovered: Query<&CurrentHovered>,
+    q_choice_items: Query<&ChoiceItem>,
+    mut selected_events: EventWriter<ChoiceSelected>,
+    mut dismissed_events: EventWriter<ChoiceDismissed>,
+) {
+    for (ui, mut _choice) in query.iter_mut() {
+        let count = _choice
+            .choices
+            .len();
+        if !_choice.is_visible || count == 0 {
+            continue;
+        }
+
+        if actions.just_pressed(Action::Cancel) {
+            _choice.is_visible = false;
+            dismissed_events.send(ChoiceDismissed { ui });
+            continue;
+        }
+
+        if _choice.selected >= count {
+            _choice.selected = 0;
+        }
+        if actions.just_pressed(Action::ChoiceDown) {
+            _choice.selected = (_choice.selected + 1) % count;
+        }
+        if actions.just_pressed(Action::ChoiceUp) {
+            _choice.selected = (_choice.selected + count - 1) % count;
+        }
+
+        let mut index = None;
+        if actions.just_pressed(Action::Confirm) {
+            index = Some(_choice.selected);
+        }
+        for (key_index, key) in CHOICE_KEYS
+            .iter()
+            .enumerate()
+            .take(count)
+        {
+            if actions
+                .keys()
+                .just_pressed(*key)
+            {
+                index = Some(key_index);
+            }
+        }
+        if mouse_input.just_pressed(MouseButton::Left) {
+            for current_hovered in q_current_hovered.iter() {
+                if let Some(item) = current_hovered
+                    .entity
+                    .and_then(|entity| q_choice_items.get(entity).ok())
+                    .filter(|item| item.index < count)
+                {
+                    index = Some(item.index);
+                }
+            }
+        }
+
+        if let Some(index) = index {
+            _choice.selected = index;
+            selected_events.send(ChoiceSelected {
+                ui,
+                index,
+                label: _choice.choices[index].clone(),
+            });
+        }
+    }
+}
+
+// 플레이어의 최근 급여 명세서를 표시
+pub fn update_payslip_ui(
+    actions: UiActionInput,
+    focus: Res<UiInputFocus>,
+    mut query: Query<(&mut Text, &mut Visibility, &mut PayslipUI)>,
+    player_query: Query<&PayslipHistory, With<Player>>,
+) {
+    for (mut _text, mut _visibility, mut _payslip_ui) in query.iter_mut() {
+        if !focus.0 && actions.just_pressed(Action::TogglePayslip) {
+            _payslip_ui.is_visible = !_payslip_ui.is_visible;
+        }
+        if !_payslip_ui.is_visible {
+            *_visibility = Visibility::Hidden;
+            continue;
+        }
+        *_visibility = Visibility::Visible;
+
+        _text.sections[0].value = actions.hint("급여 명세서 ({TogglePayslip})\n");
+        let Ok(history) = player_query.get_single() else {
+            continue;
+        };
+        if history
+            .payslips
+            .is_empty()
+        {
+            _text.sections[0]
+                .value
+                .push_str("아직 받은 월급이 없습니다.\n");
+        }
+        for payslip in history
+            .payslips
+            .iter()
+            .rev()
+            .take(MAX_PAYSLIPS_SHOWN)
+        {
+            _text.sections[0]
+                .value
+                .push_str(&format!(
+                    "{}\n  지급 {}\n  4대보험 {} / 세금 {}\n  실수령 {}\n",
+                    payslip.date,
+                    payslip.gross,
+                    show_money(payslip.insurance()),
+                    show_money(payslip.taxes()),
+                    show_money(payslip.net())
+                ));
+        }
+    }
+}
+
+const MAX_PAYSLIPS_SHOWN: usize = 3;
+
+// 계산하지 못한 금액은 오류를 대신 표시
+fn show_money(money: Result<Money, MoneyError>) -> String {
+    money.map_or_else(|error| error.to_string(), |money| money.to_string())
+}
+
+// 키 설정 화면을 열고 닫고, 동작을 골라 새 키를 받음. 열려 있는 동안은 입력이 게임에 넘어가지 않음
+pub fn rebinding_input(
+    actions: UiActionInput,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<UiInputFocus>,
+    mut query: Query<&mut RebindingUI>,
+    mut rebind_events: EventWriter<RebindAction>,
+) {
+    for mut _rebinding in query.iter_mut() {
+        if !_rebinding.is_visible {
+            if actions.just_pressed(Action::OpenMenu) {
+                _rebinding.is_visible = true;
+                _rebinding.listening = false;
+                focus.0 = true;
+            }
+            continue;
+        }
+
+        let action = Action::ALL[_rebinding.selected % Action::ALL.len()];
+        if _rebinding.listening {
+            // 취소 키는 취소에 쓰므로 연결할 수 없음
+            if actions.just_pressed(Action::Cancel) {
+                _rebinding.listening = false;
+            } else if let Some(binding) = actions.any_just_pressed() {
+                rebind_events.send(RebindAction {
+                    action,
+                    binding: Some(binding),
+                });
+                _rebinding.listening = false;
+            }
+            continue;
+        }
+
+        if actions.just_pressed(Action::OpenMenu) || actions.just_pressed(Action::Cancel) {
+            _rebinding.is_visible = false;
+            focus.0 = false;
+            continue;
+        }
+        let count = Action::ALL.len();
+        if actions.just_pressed(Action::ChoiceDown) {
+            _rebinding.selected = (_rebinding.selected + 1) % count;
+        }
+        if actions.just_pressed(Action::ChoiceUp) {
+            _rebinding.selected = (_rebinding.selected + count - 1) % count;
+        }
+        if actions.just_pressed(Action::Confirm) {
+            _rebinding.listening = true;
+        }
+        if keyboard_input.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+            rebind_events.send(RebindAction { action, binding: None });
+        }
+    }
+}
+
+// 동작마다 연결된 키를 표시
+pub fn update_rebinding_ui(
+    actions: UiActionInput,
+    mut query: Query<(&mut Text, &mut Visibility, &RebindingUI)>,
+) {
+    for (mut _text, mut _visibility, _rebinding) in query.iter_mut() {
+        if !_rebinding.is_visible {
+            *_visibility = Visibility::Hidden;
+            continue;
+        }
+        *_visibility = Visibility::Visible;
+
+        let map = actions.map();
+        _text.sections[0].value = actions.hint("키 설정 ({OpenMenu} 닫기)\n");
+        for (index, action) in Action::ALL
+            .iter()
+            .enumerate()
+        {
+            let bindings: Vec<String> = map
+                .bindings(*action)
+                .iter()
+                .map(|binding| binding.to_string())
+                .collect();
+            let cursor = if index == _rebinding.selected { ">" } else { " " };
+            _text.sections[0]
+                .value
+                .push_str(&format!("{} {}: {}\n", cursor, action, bindings.join(", ")));
+        }
+        let footer = if _rebinding.listening {
+            actions.hint("새 키나 버튼을 누르세요 ({Cancel} 취소)")
+        } else {
+            actions.hint("{ChoiceUp}/{ChoiceDown} 이동, {Confirm} 바꾸기, Delete 기본값, {Cancel} 닫기")
+        };
+        _text.sections[0]
+            .value
+            .push_str(&footer);
+    }
+}
+
+/// 상호작용 휠에 한 번에 표시하는 대상 수
+const WHEEL_SLOTS: usize = 8;
+/// 플레이어로부터 휠 항목까지의 거리
+const WHEEL_RADIUS: f32 = 110.0;
+
+// 고른 상호작용 대상의 행동을 힌트로 표시. 대상이 여럿이면 다음 대상 키도 표시
+pub fn update_interaction_hint(
+    targets: Res<InteractionTargets>,
+    action_map: Res<ActionMap>,
+    device: Res<InputDevice>,
+    mut hint_query: Query<(&mut Visibility, &mut Text, &InteractionHintUI)>,
+) {
+    // 대상 목록, 키 설정, 입력 장치가 바뀔 때만 다시 씀
+    if !targets.is_changed() && !action_map.is_changed() && !device.is_changed() {
+        return;
+    }
+    for (mut _visibility, mut _text, _hint) in hint_query.iter_mut() {
+        let Some(candidate) = targets.current() else {
+            *_visibility = Visibility::Hidden;
+            continue;
+        };
+        *_visibility = Visibility::Visible;
+        _text.sections[0].value = format!(
+            "{} {} ({})",
+            action_map.fill_hint(&_hint.text, *device),
+            candidate.label,
+            candidate.name
+        );
+        let count = targets
+            .candidates
+            .len();
+        if count > 1 {
+            _text.sections[0]
+                .value
+                .push_str(&format!(
+                    "\n{} {}/{}",
+                    action_map.fill_hint(&_hint.cycle_text, *device),
+                    targets.selected + 1,
+                    count
+                ));
+        }
+    }
+}
+
+// 주변에 상호작용 대상이 여럿이면 플레이어 둘레에 돌려 놓고 고른 대상을 강조
+pub fn update_interaction_wheel(
+    targets: Res<InteractionTargets>,
+    player_query: Query<&Transform, (With<Player>, Without<InteractionWheelItem>)>,
+    mut item_query: Query<(&mut Text, &mut Visibility, &mut Transform, &InteractionWheelItem)>,
+) {
+    let count = targets
+        .candidates
+        .len()
+        .min(WHEEL_SLOTS);
+    let center = player_query
+        .get_single()
+        .ok()
+        .filter(|_| count > 1)
+        .map(|transform| transform.translation);
+
+    for (mut _text, mut _visibility, mut _transform, _item) in item_query.iter_mut() {
+        let Some(center) = center.filter(|_| _item.index < count) else {
+            *_visibility = Visibility::Hidden;
+            _transform.translation = Transform::from_xyz(10000.0, 10000.0, 0.0).translation;
+            continue;
+        };
+        let candidate = &targets.candidates[_item.index];
+        _text.sections[0].value = format!("{} ({})", candidate.label, candidate.name);
+        _text.sections[0]
+            .style
+            .color = if _item.index == targets.selected {
+            Color::YELLOW
+        } else {
+            Color::WHITE
+        };
+        *_visibility = Visibility::Visible;
+
+        // 맨 위에서 시작해 시계 방향으로 앞 순서부터 놓음
+        let angle = std::f32::consts::FRAC_PI_2 - std::f32::consts::TAU * _item.index as f32 / count as f32;
+        _transform.translation = center + Vec3::new(angle.cos(), angle.sin(), 0.0) * WHEEL_RADIUS + Vec3::Z * 10.0;
+    }
+}
+
+const PROGRESS_BAR_SIZE: Vec2 = Vec2::new(60.0, 8.0);
+
+// 책상 위의 업무 진행 막대를 추가
+pub fn add_task_progress_bar(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let shape = Mesh2dHandle(meshes.add(Rectangle::new(PROGRESS_BAR_SIZE.x, PROGRESS_BAR_SIZE.y)));
+    commands
+        .spawn((
+            TaskProgressBar,
+            MaterialMesh2dBundle {
+                mesh: shape.clone(),
+                material: materials.add(Color::rgba(0.0, 0.0, 0.0, 0.6)),
+                transform: Transform::from_xyz(10000.0, 10000.0, 5.0),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TaskProgressFill,
+                MaterialMesh2dBundle {
+                    mesh: shape,
+                    material: materials.add(Color::rgb(0.3, 0.9, 0.4)),
+                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
+                    ..default()
+                },
+            ));
+        });
+}
+
+// 플레이어가 책상에서 일하는 동안 진행 중인 업무의 진행률을 표시
+pub fn update_task_progress_bar(
+    player_query: Query<(&TaskList, &Working), With<Player>>,
+    desk_query: Query<&GlobalTransform>,
+    mut bar_query: Query<(&mut Transform, &mut Visibility), With<TaskProgressBar>>,
+    mut fill_query: Query<&mut Transform, (With<TaskProgressFill>, Without<TaskProgressBar>)>,
+) {
+    let working = player_query
+        .get_single()
+        .ok()
+        .and_then(|(task_list, working)| {
+            let task = task_list.current()?;
+            let desk = desk_query
+                .get(working.desk)
+                .ok()?;
+            Some((task.ratio(), desk.translation()))
+        });
+
+    for (mut _transform, mut _visibility) in bar_query.iter_mut() {
+        let Some((ratio, desk_position)) = working else {
+            *_visibility = Visibility::Hidden;
+            continue;
+        };
+        *_visibility = Visibility::Visible;
+        _transform.translation = desk_position + Vec3::new(0.0, 60.0, 5.0);
+
+        for mut _fill_transform in fill_query.iter_mut() {
+            // 왼쪽 끝에 맞춰서 채움
+            _fill_transform
+                .scale
+                .x = ratio;
+            _fill_transform
+                .translation
+                .x = -PROGRESS_BAR_SIZE.x * (1.0 - ratio) / 2.0;
+        }
+    }
+}
+
 // 게임오버 팝업을 추가
 fn add_pop_up(commands: &mut Commands, font: &Res<MyFont>) {
     commands.spawn((
@@ -118,6 +578,73 @@ fn add_pop_up(commands: &mut Commands, font: &Res<MyFont>) {
     ));
 }
 
+/// 사건 팝업이 떠 있는 시간 (초)
+const INCIDENT_POP_UP_SECONDS: f32 = 4.0;
+
+// 화면 위쪽에 사건 팝업을 추가
+fn add_incident_pop_up(commands: &mut Commands, font: &Res<MyFont>) {
+    commands.spawn((
+        TextBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font_size: 36.0,
+                    color: Color::YELLOW,
+                    font: font
+                        .0
+                        .clone(),
+                },
+            )
+            .with_justify(JustifyText::Center),
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(12.0),
+                left: Val::Percent(20.0),
+                width: Val::Percent(60.0),
+                ..Default::default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        },
+        PopUpUI { text: String::new() },
+        IncidentPopUp {
+            timer: Timer::from_seconds(INCIDENT_POP_UP_SECONDS, TimerMode::Once),
+        },
+    ));
+}
+
+// 사건이 일어나면 팝업을 띄우고 잠시 뒤 숨김
+pub fn update_incident_pop_up(
+    time: Res<Time>,
+    mut occurred: EventReader<IncidentOccurred>,
+    mut query: Query<(&mut Visibility, &mut Text, &mut PopUpUI, &mut IncidentPopUp)>,
+) {
+    let latest = occurred
+        .read()
+        .last();
+    for (mut _visibility, mut _text, mut _pop_up, mut _incident) in query.iter_mut() {
+        if let Some(event) = latest {
+            _pop_up.text = format!("{}\n{}", event.incident.title, event.incident.text);
+            _text.sections[0].value = _pop_up
+                .text
+                .clone();
+            _incident
+                .timer
+                .reset();
+            *_visibility = Visibility::Visible;
+            continue;
+        }
+        if _incident
+            .timer
+            .tick(time.delta())
+            .just_finished()
+        {
+            *_visibility = Visibility::Hidden;
+        }
+    }
+}
+
 // 상호작용 힌트를 추가
 fn add_text(commands: &mut Commands, font: &Res<MyFont>) {
     commands.spawn((
@@ -136,11 +663,32 @@ fn add_text(commands: &mut Commands, font: &Res<MyFont>) {
             ..Default::default()
         },
         InteractionHintUI {
-            text: "[E]를 눌러 상호작용하기".to_string(),
+            text: "[{Interact}]를 눌러".to_string(),
+            cycle_text: "[{CycleTarget}] 다음 대상".to_string(),
         },
     ));
 }
 
+// 주변의 상호작용 대상을 표시하는 휠 항목을 추가
+fn add_interaction_wheel(commands: &mut Commands, font: &Res<MyFont>) {
+    let text_style = TextStyle {
+        font_size: 20.0,
+        color: Color::WHITE,
+        font: font
+            .0
+            .clone(),
+    };
+    for index in 0..WHEEL_SLOTS {
+        let mut text_bundle = Text2dBundle {
+            text: Text::from_section("", text_style.clone()).with_justify(JustifyText::Center),
+            transform: Transform::from_xyz(10000.0, 10000.0, 10.0),
+            ..default()
+        };
+        text_bundle.visibility = Visibility::Hidden;
+        commands.spawn((text_bundle, InteractionWheelItem { index }));
+    }
+}
+
 // 캐릭터의 상태를 표시하는 HUD를 추가
 fn add_hud(commands: &mut Commands, font: &Res<MyFont>) {
     commands.spawn((
@@ -166,6 +714,61 @@ fn add_hud(commands: &mut Commands, font: &Res<MyFont>) {
     ));
 }
 
+// 급여 명세서 목록을 추가
+fn add_payslip_ui(commands: &mut Commands, font: &Res<MyFont>) {
+    commands.spawn((
+        TextBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                    font: font
+                        .0
+                        .clone(),
+                },
+            ),
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.0),
+                top: Val::Px(10.0),
+                ..Default::default()
+            },
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        },
+        PayslipUI { is_visible: false },
+    ));
+}
+
+// 키 설정 화면을 추가
+fn add_rebinding_ui(commands: &mut Commands, font: &Res<MyFont>) {
+    commands.spawn((
+        TextBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                    font: font
+                        .0
+                        .clone(),
+                },
+            ),
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.0),
+                bottom: Val::Px(10.0),
+                ..Default::default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        },
+        RebindingUI::default(),
+    ));
+}
+
 fn add_choice_ui(commands: &mut Commands, font: &Res<MyFont>) {
     let text_style = TextStyle {
         font_size: 30.0,
@@ -183,12 +786,22 @@ fn add_choice_ui(commands: &mut Commands, font: &Res<MyFont>) {
         "선택지 5".to_string(), "선택지 6".to_string(), "선택지 7".to_string(), "선택지 8".to_string(),];
 
     let bundle = ChoiceUI {
+        prompt: String::new(),
         choices: choices_text.clone(),
+        selected: 0,
         is_visible: false,
         tranform: Transform::from_xyz(0.0, 0.0, 0.0),
     };
     commands.spawn(bundle);
 
+    let mut prompt_bundle = Text2dBundle {
+        text: Text::from_section("", text_style.clone()).with_justify(text_justification),
+        transform: Transform::from_xyz(10000.0, 10000.0, 10.0),
+        ..default()
+    };
+    prompt_bundle.visibility = Visibility::Hidden;
+    commands.spawn((prompt_bundle, ChoicePrompt));
+
     for index in 0..item_number {
         let mut text_bundle = Text2dBundle {
             text: Text::from_section("_", text_style.clone()).with_justify(text_justification),
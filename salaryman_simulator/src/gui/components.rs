@@ -2,7 +2,16 @@ use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct InteractionHintUI {
+    /// `{Interact}`처럼 동작 이름을 쓰면 지금 연결된 키 이름으로 바뀝니다.
     pub text: String,
+    /// 주변에 대상이 여럿일 때 덧붙이는 다음 대상 키 안내
+    pub cycle_text: String,
+}
+
+/// 상호작용 휠에서 `index`번째 대상을 표시하는 텍스트
+#[derive(Component)]
+pub struct InteractionWheelItem {
+    pub index: usize,
 }
 
 #[derive(Component)]
@@ -10,12 +19,23 @@ pub struct PopUpUI {
     pub text: String,
 }
 
+/// 사무실 사건을 잠시 보여주는 PopUpUI. 게임 오버 팝업과 따로 움직입니다.
+#[derive(Component)]
+pub struct IncidentPopUp {
+    /// 다 되면 팝업을 숨김
+    pub timer: Timer,
+}
+
 #[derive(Component)]
 pub struct StatusHUD;
 
 #[derive(Component, Reflect)]
 pub struct ChoiceUI {
+    /// 선택지 위에 표시하는 대사
+    pub prompt: String,
     pub choices: Vec<String>,
+    /// 강조 표시된 선택지 번호. 위/아래 화살표로 움직이고 Enter로 고릅니다.
+    pub selected: usize,
     pub is_visible: bool,
     pub tranform: Transform,
 }
@@ -24,3 +44,31 @@ pub struct ChoiceUI {
 pub struct ChoiceItem {
     pub index: usize,
 }
+
+/// ChoiceUI의 대사를 표시하는 텍스트
+#[derive(Component)]
+pub struct ChoicePrompt;
+
+/// 플레이어의 급여 명세서 목록. Tab으로 열고 닫습니다.
+#[derive(Component)]
+pub struct PayslipUI {
+    pub is_visible: bool,
+}
+
+/// 플레이어가 일하는 책상 위에 표시하는 업무 진행 막대
+#[derive(Component)]
+pub struct TaskProgressBar;
+
+/// TaskProgressBar 안에서 진행률만큼 채워지는 부분
+#[derive(Component)]
+pub struct TaskProgressFill;
+
+/// 동작별 키 설정 화면. 키 설정 키(기본 F10)로 열고 닫습니다.
+#[derive(Component, Default)]
+pub struct RebindingUI {
+    pub is_visible: bool,
+    /// 강조 표시된 동작 번호 (Action::ALL 순서)
+    pub selected: usize,
+    /// 새로 연결할 키를 기다리는 중
+    pub listening: bool,
+}
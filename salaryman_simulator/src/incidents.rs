@@ -0,0 +1,383 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::clock::{GameClock, HourStarted, Weekday};
+use crate::components::{Person, Worker};
+use crate::interaction::{InteractionActor, InteractionActorQuery};
+use crate::needs::{NeedsRestore, RestoreNeeds};
+use crate::player::Player;
+use crate::relationships::{AdjustRelationship, RelationshipKind};
+use crate::rng::SimRng;
+use crate::tasks::{Skill, Task, TaskList, Working};
+
+const OFFICE_INCIDENTS_PATH: &str = "incidents/office.incidents.ron";
+
+/// 파일에서 읽어오는 사무실 사건 목록
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct IncidentDeck {
+    /// 매시 정각에 사건이 하나 일어날 확률
+    pub chance_per_hour: f64,
+    pub incidents: Vec<Incident>,
+}
+
+/// 조건을 만족하면 가중치에 비례해서 뽑히는 사건
+#[derive(Debug, Clone, Deserialize)]
+pub struct Incident {
+    /// 다시 일어나는 간격을 셀 때 쓰는 이름
+    pub id: String,
+    pub title: String,
+    /// 팝업에 표시하는 문장
+    pub text: String,
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+    /// 모두 만족해야 일어날 수 있음
+    #[serde(default)]
+    pub conditions: Vec<IncidentCondition>,
+    /// 주인공에게 적용되는 효과
+    #[serde(default)]
+    pub effects: Vec<IncidentEffect>,
+    /// 한 번 일어나면 이만큼 날이 지나야 다시 일어남
+    #[serde(default = "default_cooldown_days")]
+    pub cooldown_days: u32,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+fn default_cooldown_days() -> u32 {
+    1
+}
+
+/// 게임 시계와 주인공의 상태로 확인하는 조건
+#[derive(Debug, Clone, Deserialize)]
+pub enum IncidentCondition {
+    /// 평일 근무 시간
+    WorkHours,
+    OffHours,
+    Weekday(Weekday),
+    /// 시작 시각 이상, 끝 시각 미만
+    HourBetween(u32, u32),
+    HpAtLeast(i32),
+    HpBelow(i32),
+    SanAtLeast(i32),
+    SanBelow(i32),
+    /// 책상에서 일하는 중
+    Working,
+    /// 맡은 업무가 있음
+    HasTask,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum IncidentEffect {
+    ChangeHp(i32),
+    ChangeSan(i32),
+    Restore(NeedsRestore),
+    /// 책상에서 일어나게 함
+    StopWork,
+    /// 업무 이름, 난이도 (게임 시간), 며칠 뒤 마감인지
+    AssignTask(String, f32, u32),
+    /// 지금 업무의 진행을 게임 시간만큼 되돌림
+    SetBackTask(f32),
+    /// 동료들의 주인공에 대한 호감이 바뀜
+    ChangeReputation(i32),
+}
+
+impl IncidentCondition {
+    pub fn is_met(&self, player: InteractionActor, clock: &GameClock) -> bool {
+        match self {
+            IncidentCondition::WorkHours => clock.is_work_hours(),
+            IncidentCondition::OffHours => !clock.is_work_hours(),
+            IncidentCondition::Weekday(weekday) => clock.weekday() == *weekday,
+            IncidentCondition::HourBetween(start, end) => (*start..*end).contains(&clock.hour()),
+            IncidentCondition::HpAtLeast(hp) => player.person.hp >= *hp,
+            IncidentCondition::HpBelow(hp) => player.person.hp < *hp,
+            IncidentCondition::SanAtLeast(san) => player.person.san >= *san,
+            IncidentCondition::SanBelow(san) => player.person.san < *san,
+            IncidentCondition::Working => player.working,
+            IncidentCondition::HasTask => player
+                .task_list
+                .is_some_and(|task_list| {
+                    task_list
+                        .current()
+                        .is_some()
+                }),
+        }
+    }
+}
+
+impl IncidentDeck {
+    /// 사건 이름이 겹치지 않고 가중치가 양수인지 확인
+    fn validate(&self) -> Result<(), IncidentLoaderError> {
+        let mut ids = HashSet::new();
+        for incident in self
+            .incidents
+            .iter()
+        {
+            if !ids.insert(incident.id.as_str()) {
+                return Err(IncidentLoaderError::DuplicateId(
+                    incident
+                        .id
+                        .clone(),
+                ));
+            }
+            if incident.weight <= 0.0 {
+                return Err(IncidentLoaderError::InvalidWeight(
+                    incident
+                        .id
+                        .clone(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 사건을 뽑는 감독. 사건마다 마지막으로 일어난 날을 기억합니다.
+///
+/// 마지막으로 일어난 날은 저장 파일에도 들어가서, 불러온 뒤에도 같은 사건이 일어납니다.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct IncidentDirector {
+    #[reflect(ignore)]
+    pub deck: Handle<IncidentDeck>,
+    pub last_days: HashMap<String, u32>,
+}
+
+impl IncidentDirector {
+    fn is_ready(&self, incident: &Incident, day: u32) -> bool {
+        self.last_days
+            .get(&incident.id)
+            .is_none_or(|last_day| day >= last_day + incident.cooldown_days)
+    }
+}
+
+/// 사건이 일어남. 효과가 적용되고 팝업이 뜹니다.
+#[derive(Event, Debug, Clone)]
+pub struct IncidentOccurred {
+    pub incident: Incident,
+}
+
+#[derive(Default)]
+pub struct IncidentDeckLoader;
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum IncidentLoaderError {
+    #[error("Could not load incidents: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse RON incidents: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Incident id {0:?} is used more than once")]
+    DuplicateId(String),
+    #[error("Incident {0:?} must have a positive weight")]
+    InvalidWeight(String),
+}
+
+impl AssetLoader for IncidentDeckLoader {
+    type Asset = IncidentDeck;
+    type Settings = ();
+    type Error = IncidentLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await?;
+            let deck = ron::de::from_bytes::<IncidentDeck>(&bytes)?;
+            deck.validate()?;
+            Ok(deck)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["incidents.ron"]
+    }
+}
+
+pub struct IncidentPlugin;
+
+impl Plugin for IncidentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<IncidentDeck>()
+            .init_asset_loader::<IncidentDeckLoader>()
+            .init_resource::<IncidentDirector>()
+            .add_event::<IncidentOccurred>()
+            .add_systems(Startup, load_incidents)
+            .add_systems(
+                FixedUpdate,
+                (direct_incidents, apply_incidents)
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+fn load_incidents(asset_server: Res<AssetServer>, mut director: ResMut<IncidentDirector>) {
+    director.deck = asset_server.load(OFFICE_INCIDENTS_PATH);
+}
+
+// 매시 정각마다 확률에 따라 조건을 만족하는 사건 하나를 가중치로 뽑음
+// 시각마다 따로 갈라진 난수를 써서 저장 파일을 불러와도 같은 사건이 일어남
+fn direct_incidents(
+    mut hour_started: EventReader<HourStarted>,
+    clock: Res<GameClock>,
+    sim_rng: Res<SimRng>,
+    decks: Res<Assets<IncidentDeck>>,
+    mut director: ResMut<IncidentDirector>,
+    q_player: Query<InteractionActorQuery, With<Player>>,
+    mut occurred: EventWriter<IncidentOccurred>,
+) {
+    let Some(event) = hour_started
+        .read()
+        .last()
+    else {
+        return;
+    };
+    let (Some(deck), Ok(player)) = (decks.get(&director.deck), q_player.get_single()) else {
+        return;
+    };
+    let player = player.actor();
+    if player
+        .person
+        .is_dead
+    {
+        return;
+    }
+
+    let mut rng = sim_rng.fork(&format!("incidents {} {}", event.day, event.hour));
+    if !rng.gen_bool(
+        deck.chance_per_hour
+            .clamp(0.0, 1.0),
+    ) {
+        return;
+    }
+    let eligible: Vec<&Incident> = deck
+        .incidents
+        .iter()
+        .filter(|incident| director.is_ready(incident, event.day))
+        .filter(|incident| {
+            incident
+                .conditions
+                .iter()
+                .all(|condition| condition.is_met(player, &clock))
+        })
+        .collect();
+    let Ok(incident) = eligible.choose_weighted(&mut rng, |incident| incident.weight) else {
+        return;
+    };
+
+    info!("사건 발생: {} - {}", incident.title, incident.text);
+    director
+        .last_days
+        .insert(
+            incident
+                .id
+                .clone(),
+            event.day,
+        );
+    occurred.send(IncidentOccurred {
+        incident: (*incident).clone(),
+    });
+}
+
+/// 사건의 효과를 받는 플레이어
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct IncidentTarget {
+    entity: Entity,
+    person: &'static mut Person,
+    task_list: Option<&'static mut TaskList>,
+    worker: Option<&'static Worker>,
+}
+
+fn apply_incidents(
+    mut commands: Commands,
+    mut occurred: EventReader<IncidentOccurred>,
+    clock: Res<GameClock>,
+    mut q_player: Query<IncidentTarget, With<Player>>,
+    q_coworkers: Query<Entity, (With<Person>, Without<Player>)>,
+    mut restore: EventWriter<RestoreNeeds>,
+    mut adjust: EventWriter<AdjustRelationship>,
+) {
+    for event in occurred.read() {
+        let Ok(IncidentTargetItem {
+            entity: player,
+            mut person,
+            mut task_list,
+            worker,
+        }) = q_player.get_single_mut()
+        else {
+            continue;
+        };
+        for effect in event
+            .incident
+            .effects
+            .iter()
+        {
+            match effect {
+                IncidentEffect::ChangeHp(amount) => person.change_hp(*amount),
+                IncidentEffect::ChangeSan(amount) => person.change_san(*amount),
+                IncidentEffect::Restore(source) => {
+                    restore.send(RestoreNeeds {
+                        entity: player,
+                        source: *source,
+                    });
+                }
+                IncidentEffect::StopWork => {
+                    commands
+                        .entity(player)
+                        .remove::<Working>();
+                }
+                IncidentEffect::AssignTask(title, difficulty, days) => {
+                    let Some(task_list) = task_list.as_mut() else {
+                        continue;
+                    };
+                    let skill = worker.map_or(Skill::Paperwork, |worker| Skill::for_role(&worker.role));
+                    task_list
+                        .tasks
+                        .push(Task {
+                            title: title.clone(),
+                            difficulty: *difficulty,
+                            skill,
+                            deadline_day: clock.day() + days,
+                            progress: 0.0,
+                        });
+                }
+                IncidentEffect::SetBackTask(hours) => {
+                    if let Some(task) = task_list
+                        .as_mut()
+                        .and_then(|task_list| task_list.current_mut())
+                    {
+                        task.progress = (task.progress - hours).max(0.0);
+                    }
+                }
+                IncidentEffect::ChangeReputation(amount) => {
+                    for coworker in q_coworkers.iter() {
+                        adjust.send(AdjustRelationship {
+                            entity: coworker,
+                            other: player,
+                            kind: RelationshipKind::Affinity,
+                            amount: *amount,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,473 @@
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::GameClock;
+use crate::components::{Interactable, Person};
+use crate::dialogue::StartDialogue;
+use crate::needs::{NeedsRestore, RestoreNeeds};
+use crate::tasks::{HelpWithTask, TaskList, Working};
+
+/// 이 거리 안에 있어야 상호작용할 수 있고, 멀어지면 하던 상호작용이 취소됨
+pub const INTERACT_DISTANCE: f32 = 60.0;
+/// 바라보는 쪽에 있는 대상은 거리를 최대 이 비율만큼 가깝게 침 (0이면 거리만 봄)
+const FACING_WEIGHT: f32 = 0.5;
+
+/// 상호작용 대상에서 할 수 있는 행동 하나
+#[derive(Reflect, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionAction {
+    /// 힌트에 표시하는 행동 이름
+    pub label: String,
+    /// 모두 만족해야 할 수 있음
+    #[serde(default)]
+    pub conditions: Vec<InteractionCondition>,
+    /// 끝날 때까지 걸리는 게임 시간. 0이면 바로 끝남
+    #[serde(default)]
+    pub hours: f32,
+    /// 끝나면 적용되는 효과
+    #[serde(default)]
+    pub effects: Vec<InteractionEffect>,
+}
+
+impl InteractionAction {
+    pub fn new(label: &str, conditions: Vec<InteractionCondition>, hours: f32, effects: Vec<InteractionEffect>) -> Self {
+        InteractionAction {
+            label: label.to_string(),
+            conditions,
+            hours,
+            effects,
+        }
+    }
+}
+
+/// 상호작용하는 사람과 시계로 확인하는 조건
+#[derive(Reflect, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InteractionCondition {
+    /// 평일 근무 시간
+    WorkHours,
+    OffHours,
+    /// 책상에서 일하는 중
+    Working,
+    NotWorking,
+    /// 맡은 업무가 있음
+    HasTask,
+    HpAtLeast(i32),
+    SanAtLeast(i32),
+}
+
+/// 상호작용이 끝나면 적용되는 효과. 대상은 상호작용한 물건이나 사람입니다.
+#[derive(Reflect, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InteractionEffect {
+    /// 대상 책상에서 일을 시작
+    StartWork,
+    StopWork,
+    /// 대상과 대화를 시작
+    StartDialogue,
+    ChangeHp(i32),
+    ChangeSan(i32),
+    Restore(NeedsRestore),
+    /// 자기 업무를 게임 시간만큼 진행
+    ProgressTask(f32),
+}
+
+/// 조건을 확인할 때 보는 상호작용하는 사람의 상태
+#[derive(Clone, Copy)]
+pub struct InteractionActor<'a> {
+    pub person: &'a Person,
+    pub working: bool,
+    pub task_list: Option<&'a TaskList>,
+}
+
+/// 상호작용하는 사람의 상태를 읽는 쿼리
+#[derive(QueryData)]
+pub struct InteractionActorQuery {
+    person: &'static Person,
+    working: Has<Working>,
+    task_list: Option<&'static TaskList>,
+}
+
+impl InteractionActorQueryItem<'_> {
+    pub fn actor(&self) -> InteractionActor<'_> {
+        InteractionActor {
+            person: self.person,
+            working: self.working,
+            task_list: self.task_list,
+        }
+    }
+}
+
+impl InteractionCondition {
+    pub fn is_met(&self, actor: InteractionActor, clock: &GameClock) -> bool {
+        match self {
+            InteractionCondition::WorkHours => clock.is_work_hours(),
+            InteractionCondition::OffHours => !clock.is_work_hours(),
+            InteractionCondition::Working => actor.working,
+            InteractionCondition::NotWorking => !actor.working,
+            InteractionCondition::HasTask => actor
+                .task_list
+                .is_some_and(|task_list| {
+                    task_list
+                        .current()
+                        .is_some()
+                }),
+            InteractionCondition::HpAtLeast(hp) => actor.person.hp >= *hp,
+            InteractionCondition::SanAtLeast(san) => actor.person.san >= *san,
+        }
+    }
+}
+
+impl Interactable {
+    /// 조건을 만족하는 첫 행동의 번호
+    pub fn available_action(&self, actor: InteractionActor, clock: &GameClock) -> Option<usize> {
+        self.actions
+            .iter()
+            .position(|action| {
+                action
+                    .conditions
+                    .iter()
+                    .all(|condition| condition.is_met(actor, clock))
+            })
+    }
+
+    pub fn desk() -> Self {
+        use InteractionCondition::*;
+        use InteractionEffect::*;
+        Interactable {
+            actions: vec![
+                InteractionAction::new(
+                    "일 시작하기",
+                    vec![NotWorking],
+                    0.0,
+                    vec![StartWork, Restore(NeedsRestore::Work)],
+                ),
+                InteractionAction::new("일 멈추기", vec![Working], 0.0, vec![StopWork]),
+            ],
+        }
+    }
+
+    pub fn coworker() -> Self {
+        Interactable {
+            actions: vec![InteractionAction::new("말 걸기", vec![], 0.0, vec![InteractionEffect::StartDialogue])],
+        }
+    }
+
+    pub fn coffee_machine() -> Self {
+        Interactable {
+            actions: vec![InteractionAction::new(
+                "커피 뽑기",
+                vec![],
+                0.1,
+                vec![InteractionEffect::Restore(NeedsRestore::Coffee)],
+            )],
+        }
+    }
+
+    pub fn printer() -> Self {
+        use InteractionCondition::*;
+        use InteractionEffect::*;
+        Interactable {
+            actions: vec![
+                InteractionAction::new("서류 출력하기", vec![WorkHours, HasTask], 0.25, vec![ProgressTask(0.5)]),
+                // 할 일이 없으면 종이만 걸림
+                InteractionAction::new("프린터 만지기", vec![], 0.1, vec![ChangeSan(-2)]),
+            ],
+        }
+    }
+
+    pub fn elevator() -> Self {
+        use InteractionCondition::*;
+        use InteractionEffect::*;
+        Interactable {
+            actions: vec![
+                InteractionAction::new("퇴근하기", vec![OffHours], 0.1, vec![Restore(NeedsRestore::GoHome)]),
+                InteractionAction::new("바람 쐬고 오기", vec![WorkHours], 0.25, vec![ChangeSan(5)]),
+            ],
+        }
+    }
+
+    pub fn boss_door() -> Self {
+        use InteractionCondition::*;
+        use InteractionEffect::*;
+        Interactable {
+            actions: vec![
+                InteractionAction::new(
+                    "결재 받기",
+                    vec![WorkHours, HasTask, SanAtLeast(20)],
+                    0.5,
+                    vec![ChangeSan(-10), ProgressTask(1.0)],
+                ),
+                InteractionAction::new("노크만 하고 도망가기", vec![], 0.0, vec![ChangeSan(-1)]),
+            ],
+        }
+    }
+
+    /// 배치도의 물건 종류에 맞는 상호작용. 모르는 종류면 None
+    pub fn for_object(kind: &str) -> Option<Self> {
+        match kind {
+            "coffee_machine" => Some(Self::coffee_machine()),
+            "printer" => Some(Self::printer()),
+            "elevator" => Some(Self::elevator()),
+            "boss_door" => Some(Self::boss_door()),
+            _ => None,
+        }
+    }
+}
+
+/// 가까이 있어서 지금 상호작용할 수 있는 대상 하나
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct InteractionCandidate {
+    pub target: Entity,
+    /// 조건을 만족하는 첫 행동의 번호
+    pub action: usize,
+    pub label: String,
+    pub name: String,
+}
+
+/// 플레이어 주변의 상호작용 대상들. 가깝고 바라보는 쪽에 있는 대상이 앞에 옵니다.
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct InteractionTargets {
+    pub candidates: Vec<InteractionCandidate>,
+    /// 고른 대상 번호. 다음 대상 키로 돌아가며 고릅니다.
+    pub selected: usize,
+}
+
+impl InteractionTargets {
+    pub fn current(&self) -> Option<&InteractionCandidate> {
+        self.candidates
+            .get(self.selected)
+    }
+
+    pub fn cycle(&mut self) {
+        if !self
+            .candidates
+            .is_empty()
+        {
+            self.selected = (self.selected + 1) % self.candidates.len();
+        }
+    }
+
+    /// 대상 목록을 바꿈. 고른 대상이 아직 있으면 순서가 바뀌어도 계속 고름
+    pub fn replace(&mut self, candidates: Vec<InteractionCandidate>) {
+        let selected = self
+            .current()
+            .map(|candidate| candidate.target);
+        self.selected = selected
+            .and_then(|target| {
+                candidates
+                    .iter()
+                    .position(|candidate| candidate.target == target)
+            })
+            .unwrap_or(0);
+        self.candidates = candidates;
+    }
+}
+
+/// 대상을 정렬하는 점수. 작을수록 앞에 옵니다.
+pub fn target_score(offset: Vec2, facing: Vec2) -> f32 {
+    let alignment = offset
+        .normalize_or_zero()
+        .dot(facing.normalize_or_zero());
+    offset.length() * (1.0 - FACING_WEIGHT * alignment)
+}
+
+/// 시간이 걸리는 상호작용을 하는 중. 대상에게서 멀어지면 취소됩니다.
+#[derive(Component, Debug, Clone)]
+pub struct Interacting {
+    pub target: Entity,
+    pub action: usize,
+    pub label: String,
+    pub hours: f32,
+    pub elapsed_hours: f32,
+}
+
+impl Interacting {
+    /// 진행률 (0 ~ 1)
+    pub fn ratio(&self) -> f32 {
+        (self.elapsed_hours / self.hours.max(f32::EPSILON)).clamp(0.0, 1.0)
+    }
+}
+
+/// `actor`가 `target`의 `action`번 행동을 시작
+#[derive(Event, Debug, Clone, Copy)]
+pub struct InteractionStarted {
+    pub actor: Entity,
+    pub target: Entity,
+    pub action: usize,
+}
+
+/// 상호작용이 끝나서 효과가 적용됨
+#[derive(Event, Debug, Clone, Copy)]
+pub struct InteractionCompleted {
+    pub actor: Entity,
+    pub target: Entity,
+    pub action: usize,
+}
+
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InteractionTargets>()
+            .add_event::<InteractionStarted>()
+            .add_event::<InteractionCompleted>()
+            .add_systems(
+                FixedUpdate,
+                (begin_interactions, progress_interactions, apply_interaction_effects)
+                    .chain()
+                    .after(crate::player::interact)
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 바로 끝나는 행동은 효과를 적용하고, 시간이 걸리는 행동은 진행을 시작
+fn begin_interactions(
+    mut commands: Commands,
+    mut started: EventReader<InteractionStarted>,
+    q_interactables: Query<&Interactable>,
+    mut completed: EventWriter<InteractionCompleted>,
+) {
+    for event in started.read() {
+        let Some(action) = q_interactables
+            .get(event.target)
+            .ok()
+            .and_then(|interactable| {
+                interactable
+                    .actions
+                    .get(event.action)
+            })
+        else {
+            continue;
+        };
+        if action.hours <= 0.0 {
+            completed.send(InteractionCompleted {
+                actor: event.actor,
+                target: event.target,
+                action: event.action,
+            });
+            continue;
+        }
+        commands
+            .entity(event.actor)
+            .insert(Interacting {
+                target: event.target,
+                action: event.action,
+                label: action
+                    .label
+                    .clone(),
+                hours: action.hours,
+                elapsed_hours: 0.0,
+            });
+    }
+}
+
+// 게임 시간이 흐르는 만큼 진행하고, 대상에게서 멀어지면 취소
+fn progress_interactions(
+    mut commands: Commands,
+    time: Res<Time>,
+    clock: Res<GameClock>,
+    mut q_actors: Query<(Entity, &Transform, &mut Interacting)>,
+    q_targets: Query<&Transform>,
+    mut completed: EventWriter<InteractionCompleted>,
+) {
+    let hours = clock.scaled_delta_hours(&time);
+
+    for (actor, transform, mut interacting) in q_actors.iter_mut() {
+        let in_range = q_targets
+            .get(interacting.target)
+            .is_ok_and(|target| {
+                target
+                    .translation
+                    .distance(transform.translation)
+                    < INTERACT_DISTANCE
+            });
+        if !in_range {
+            commands
+                .entity(actor)
+                .remove::<Interacting>();
+            continue;
+        }
+        interacting.elapsed_hours += hours;
+        if interacting.elapsed_hours < interacting.hours {
+            continue;
+        }
+        commands
+            .entity(actor)
+            .remove::<Interacting>();
+        completed.send(InteractionCompleted {
+            actor,
+            target: interacting.target,
+            action: interacting.action,
+        });
+    }
+}
+
+fn apply_interaction_effects(
+    mut commands: Commands,
+    mut completed: EventReader<InteractionCompleted>,
+    q_interactables: Query<&Interactable>,
+    mut q_people: Query<&mut Person>,
+    mut start_dialogue: EventWriter<StartDialogue>,
+    mut restore: EventWriter<RestoreNeeds>,
+    mut help: EventWriter<HelpWithTask>,
+) {
+    for event in completed.read() {
+        let Some(action) = q_interactables
+            .get(event.target)
+            .ok()
+            .and_then(|interactable| {
+                interactable
+                    .actions
+                    .get(event.action)
+            })
+        else {
+            continue;
+        };
+        for effect in action
+            .effects
+            .iter()
+        {
+            match effect {
+                InteractionEffect::StartWork => {
+                    commands
+                        .entity(event.actor)
+                        .insert(Working { desk: event.target });
+                }
+                InteractionEffect::StopWork => {
+                    commands
+                        .entity(event.actor)
+                        .remove::<Working>();
+                }
+                InteractionEffect::StartDialogue => {
+                    start_dialogue.send(StartDialogue {
+                        partner: event.target,
+                    });
+                }
+                InteractionEffect::ChangeHp(amount) => {
+                    if let Ok(mut person) = q_people.get_mut(event.actor) {
+                        person.change_hp(*amount);
+                    }
+                }
+                InteractionEffect::ChangeSan(amount) => {
+                    if let Ok(mut person) = q_people.get_mut(event.actor) {
+                        person.change_san(*amount);
+                    }
+                }
+                InteractionEffect::Restore(source) => {
+                    restore.send(RestoreNeeds {
+                        entity: event.actor,
+                        source: *source,
+                    });
+                }
+                InteractionEffect::ProgressTask(hours) => {
+                    help.send(HelpWithTask {
+                        helper: event.actor,
+                        worker: event.actor,
+                        hours: *hours,
+                    });
+                }
+            }
+        }
+    }
+}
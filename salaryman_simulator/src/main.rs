@@ -1,32 +1,185 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use rand::Rng;
+
 use bevy::{
+    app::PluginsState,
+    ecs::query::QueryData,
+    input::InputPlugin,
     prelude::*,
-    sprite::{MaterialMesh2dBundle, Mesh2dHandle},
+    sprite::Mesh2dHandle,
+    time::TimeUpdateStrategy,
 };
 
+use board_plugin::{
+    components::{BoardEntity, Coordinates},
+    resources::{BoardLayout, BoardOptions, ObjectSpawn, OfficeLayout, Tile, TileMap},
+    BoardPlugin,
+};
 #[cfg(feature = "debug")]
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 
+pub mod actions;
+pub mod career;
+pub mod clock;
 pub mod components;
+pub mod dialogue;
 pub mod gui;
+pub mod incidents;
+pub mod interaction;
+pub mod money;
 pub mod mouse_event;
+pub mod navigation;
+pub mod needs;
+pub mod npc;
+pub mod payroll;
+pub mod physics;
 pub mod player;
+pub mod relationships;
+pub mod replay;
+pub mod rng;
+pub mod ron_tree;
+pub mod rumors;
+pub mod save;
+pub mod tasks;
+pub mod tick;
 
 use components::{
-    CurrentHovered, Desk, Interactable, InteractionTarget, InteractionType, MouseInput, MouseSelectable, Person, Salary, Worker
+    CurrentHovered, Desk, Interactable, MouseInput, MouseSelectable, Person, PlayTime, Salary, Wall, Worker,
+    DESK_SHAPE, OBJECT_SHAPE, PERSON_SHAPE,
 };
+use actions::{ActionMap, ActionsPlugin, UiActionInput};
+use career::{CareerOutcome, CareerPlugin, CareerRecord, Fired, Grade, JobFamily, PerformanceReview, Rank};
+use clock::{ClockPlugin, ClockSet, GameClock, GameDate};
+use dialogue::DialoguePlugin;
 use gui::components::{ChoiceUI, StatusHUD};
+use incidents::{IncidentDirector, IncidentPlugin};
+use interaction::{
+    InteractionAction, InteractionCandidate, InteractionCondition, InteractionEffect, InteractionPlugin, InteractionTargets,
+    Interacting,
+};
+use money::{Currency, ExchangeRates, Money};
+use navigation::{NavBlocker, NavigationPlugin, NavigationSet};
+use needs::{Burnout, Needs, NeedsConfig, NeedsPlugin, NeedsRestore};
+use npc::{Activity, NpcBrain, NpcPlugin, Schedule, ScheduleBlock};
+use payroll::{PayrollPlugin, Payslip, PayslipHistory, Wallet};
+use physics::{Collider, ColliderShape, PhysicsPlugin, StaticCollider};
 use player::Player;
+use relationships::{Relationship, RelationshipKind, RelationshipPlugin, Relationships};
+use replay::{InputRecorder, InputRecording, InputReplay, ReplayOutcome, ReplayPlugin};
+use rng::{RngPlugin, SimRng};
+use rumors::{Rumor, RumorKnower, RumorPlugin, RumorTopic};
+use save::{SavePlugin, SaveSlot};
+use tasks::{
+    Manager, Performance, Skill, Skills, Task, TaskConfig, TaskList, TaskPlugin, TaskTemplate, Working,
+};
+use tick::{InterpolationPlugin, TickPlugin, TickRate, TransformInterpolation};
 
 fn main() {
     let mut app = App::new();
-    app.add_plugins((DefaultPlugins, HelloPlugin));
-    // 인스펙터에 표시하기 위해 타입들을 등록
-    app.register_type::<Vec<String>>();
-    app.register_type::<Person>();
-    app.register_type::<Worker>();
-    app.register_type::<Salary>();
-    app.register_type::<InteractionTarget>();
-    app.register_type::<ChoiceUI>();
+
+    // --headless [틱 수]: 창 없이 시뮬레이션만 돌리고 최종 상태를 출력
+    // --load <슬롯>, --save <슬롯>: headless 실행 전후로 저장 파일을 불러오거나 저장
+    let args: Vec<String> = std::env::args().collect();
+    let headless = args
+        .iter()
+        .any(|arg| arg == "--headless")
+        .then(|| HeadlessOptions {
+            ticks: arg_value(&args, "--headless")
+                .and_then(|ticks| ticks.parse().ok())
+                .unwrap_or(DEFAULT_HEADLESS_TICKS),
+            load_slot: arg_value(&args, "--load").and_then(|slot| slot.parse().ok()),
+            save_slot: arg_value(&args, "--save").and_then(|slot| slot.parse().ok()),
+        });
+
+    // --replay <파일>: 기록한 입력을 재생. 시드, 틱 속도, 시간 배율, 배치도는 기록 파일을 따름
+    let replay = arg_value(&args, "--replay").map(|path| match InputRecording::load(Path::new(path)) {
+        Ok(recording) => recording,
+        Err(error) => {
+            eprintln!("could not load replay {}: {}", path, error);
+            std::process::exit(1);
+        }
+    });
+
+    // --layout <경로>: assets 폴더 기준 사무실 배치도 파일
+    let layout_path = match &replay {
+        Some(recording) => recording
+            .layout_path
+            .clone(),
+        None => arg_value(&args, "--layout")
+            .cloned()
+            .unwrap_or(DEFAULT_LAYOUT_PATH.to_string()),
+    };
+    // --time-scale <배율>: 현실 1초 동안 흐르는 게임 시간 (초)
+    let time_scale = match &replay {
+        Some(recording) => recording.time_scale,
+        None => arg_value(&args, "--time-scale")
+            .and_then(|scale| scale.parse().ok())
+            .unwrap_or(GameClock::default().time_scale),
+    };
+    app.insert_resource(GameClock {
+        time_scale,
+        ..default()
+    });
+    // --tick-rate <횟수>: 초당 시뮬레이션 틱 수
+    let tick_rate = match &replay {
+        Some(recording) => TickRate(recording.tick_rate),
+        None => arg_value(&args, "--tick-rate")
+            .and_then(|rate| rate.parse().ok())
+            .filter(|rate: &f64| *rate > 0.0)
+            .map(TickRate)
+            .unwrap_or_default(),
+    };
+    app.insert_resource(tick_rate);
+    // --seed <시드>: 같은 시드면 같은 게임이 재현됨 (없으면 무작위 시드)
+    let sim_rng = match &replay {
+        Some(recording) => SimRng::new(recording.seed),
+        None => arg_value(&args, "--seed")
+            .and_then(|seed| seed.parse().ok())
+            .map(SimRng::new)
+            .unwrap_or_default(),
+    };
+    // 키 설정: 재생할 때는 기록 파일에 담긴 키 설정을 씀
+    let action_map = match &replay {
+        Some(recording) => recording
+            .bindings
+            .clone(),
+        None => ActionMap::load_or_default(Path::new(actions::INPUT_CONFIG_PATH)),
+    };
+    // --record <파일>: 틱마다 입력을 기록했다가 끝날 때 최종 상태와 함께 저장
+    if let Some(path) = arg_value(&args, "--record") {
+        let recording = InputRecording::new(
+            sim_rng.seed(),
+            tick_rate.0,
+            time_scale,
+            layout_path.clone(),
+            action_map.clone(),
+        );
+        app.insert_resource(InputRecorder::new(PathBuf::from(path), recording));
+    }
+    if let Some(recording) = replay {
+        app.insert_resource(InputReplay::new(recording));
+    }
+    app.insert_resource(sim_rng);
+    app.insert_resource(action_map);
+    app.insert_resource(BoardOptions {
+        layout_path: Some(layout_path),
+        ..default()
+    });
+
+    if headless.is_some() {
+        add_headless_plugins(&mut app, tick_rate);
+    } else {
+        app.add_plugins((DefaultPlugins, HelloPlugin));
+    }
+
+    register_types(&mut app);
+
+    if let Some(options) = headless {
+        run_headless(&mut app, options);
+        return;
+    }
 
     #[cfg(feature = "debug")]
     // Debug hierarchy inspector
@@ -37,121 +190,577 @@ fn main() {
     app.run();
 }
 
+// 인스펙터와 저장 파일에서 쓰기 위해 타입들을 등록
+fn register_types(app: &mut App) {
+    app.register_type::<Vec<String>>();
+    app.register_type::<Person>();
+    app.register_type::<Worker>();
+    app.register_type::<Salary>();
+    app.register_type::<InteractionCandidate>();
+    app.register_type::<Vec<InteractionCandidate>>();
+    app.register_type::<InteractionTargets>();
+    app.register_type::<ChoiceUI>();
+    app.register_type::<Transform>();
+    app.register_type::<Player>();
+    app.register_type::<Desk>();
+    app.register_type::<Interactable>();
+    app.register_type::<InteractionAction>();
+    app.register_type::<Vec<InteractionAction>>();
+    app.register_type::<InteractionCondition>();
+    app.register_type::<Vec<InteractionCondition>>();
+    app.register_type::<InteractionEffect>();
+    app.register_type::<Vec<InteractionEffect>>();
+    app.register_type::<PlayTime>();
+    app.register_type::<HashMap<String, u64>>();
+    app.register_type::<SimRng>();
+    app.register_type::<HashMap<String, u32>>();
+    app.register_type::<IncidentDirector>();
+    app.register_type::<GameClock>();
+    app.register_type::<GameDate>();
+    app.register_type::<Money>();
+    app.register_type::<Currency>();
+    app.register_type::<ExchangeRates>();
+    app.register_type::<Wallet>();
+    app.register_type::<Payslip>();
+    app.register_type::<Vec<Payslip>>();
+    app.register_type::<PayslipHistory>();
+    app.register_type::<Needs>();
+    app.register_type::<Burnout>();
+    app.register_type::<NeedsRestore>();
+    app.register_type::<NeedsConfig>();
+    app.register_type::<Skill>();
+    app.register_type::<Skills>();
+    app.register_type::<Task>();
+    app.register_type::<Vec<Task>>();
+    app.register_type::<TaskList>();
+    app.register_type::<Performance>();
+    app.register_type::<Manager>();
+    app.register_type::<TaskTemplate>();
+    app.register_type::<TaskConfig>();
+    app.register_type::<Rank>();
+    app.register_type::<JobFamily>();
+    app.register_type::<Grade>();
+    app.register_type::<CareerOutcome>();
+    app.register_type::<PerformanceReview>();
+    app.register_type::<Vec<PerformanceReview>>();
+    app.register_type::<CareerRecord>();
+    app.register_type::<Fired>();
+    app.register_type::<NavBlocker>();
+    app.register_type::<ColliderShape>();
+    app.register_type::<Collider>();
+    app.register_type::<StaticCollider>();
+    app.register_type::<Wall>();
+    app.register_type::<Activity>();
+    app.register_type::<ScheduleBlock>();
+    app.register_type::<Vec<ScheduleBlock>>();
+    app.register_type::<Schedule>();
+    app.register_type::<RelationshipKind>();
+    app.register_type::<Relationship>();
+    app.register_type::<Vec<Relationship>>();
+    app.register_type::<Relationships>();
+    app.register_type::<RumorTopic>();
+    app.register_type::<RumorKnower>();
+    app.register_type::<Vec<RumorKnower>>();
+    app.register_type::<Rumor>();
+    app.register_type::<BoardEntity>();
+    app.register_type::<Coordinates>();
+}
+
+const DEFAULT_LAYOUT_PATH: &str = "layouts/office.layout.ron";
+const DEFAULT_HEADLESS_TICKS: u32 = 600;
+const LAYOUT_WAIT_TICKS: u32 = 600;
+
+struct HeadlessOptions {
+    ticks: u32,
+    load_slot: Option<u8>,
+    save_slot: Option<u8>,
+}
+
+// `--인자 값` 형태로 주어진 명령줄 인자의 값
+fn arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a String> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|index| args.get(index + 1))
+}
+
+// 창 없이 시뮬레이션만 돌리는 플러그인 구성
+fn add_headless_plugins(app: &mut App, tick_rate: TickRate) {
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        InputPlugin,
+        SimulationPlugin,
+    ));
+    // 실제 시간과 무관하게 업데이트마다 정확히 한 틱만큼 진행
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(tick_rate.timestep()));
+    app.add_systems(Startup, add_headless_ui);
+}
+
+// 러너 없이 직접 update를 부르기 전에 플러그인 준비를 마침
+fn finish_plugins(app: &mut App) {
+    while app.plugins_state() == PluginsState::Adding {
+        bevy::tasks::tick_global_task_pools_on_main_thread();
+    }
+    app.finish();
+    app.cleanup();
+}
+
+// 재생이 끝날 때까지 진행하고 진행한 업데이트 수를 돌려줌 (배치도 로딩을 기다리는 틱은 따로 셈)
+fn run_replay(app: &mut App, ticks: u64) -> u64 {
+    let max_updates = ticks + LAYOUT_WAIT_TICKS as u64;
+    let mut updates = 0;
+    while !app
+        .world
+        .contains_resource::<ReplayOutcome>()
+        && updates < max_updates
+    {
+        app.update();
+        updates += 1;
+    }
+    updates
+}
+
+// 러너 없이 직접 틱을 진행한 뒤 Person/Worker 상태를 출력
+fn run_headless(app: &mut App, options: HeadlessOptions) {
+    finish_plugins(app);
+
+    if let Some(slot) = options.load_slot {
+        // 배치도로 동료들이 배치된 뒤에 불러와야 덮어쓰이지 않음
+        for _ in 0..LAYOUT_WAIT_TICKS {
+            if app
+                .world
+                .contains_resource::<TileMap>()
+            {
+                break;
+            }
+            app.update();
+        }
+        app.update();
+        if let Err(error) = save::load_from_slot(&mut app.world, slot) {
+            eprintln!("[headless] could not load slot {}: {}", slot, error);
+        }
+    }
+
+    // 재생할 때는 기록된 틱 수만큼 진행
+    let replay_ticks = app
+        .world
+        .get_resource::<InputReplay>()
+        .map(|replay| replay.ticks());
+    let updates = match replay_ticks {
+        Some(ticks) => run_replay(app, ticks),
+        None => {
+            for _ in 0..options.ticks {
+                app.update();
+            }
+            options.ticks as u64
+        }
+    };
+
+    match replay::finish_recording(&mut app.world) {
+        Ok(Some(path)) => println!("[headless] 입력 기록 저장: {}", path.display()),
+        Ok(None) => {}
+        Err(error) => eprintln!("[headless] could not save input recording: {}", error),
+    }
+
+    if let Some(slot) = options.save_slot {
+        if let Err(error) = save::save_to_slot(&mut app.world, slot) {
+            eprintln!("[headless] could not save slot {}: {}", slot, error);
+        }
+    }
+
+    println!(
+        "[headless] {} ticks, 지난 시간 {:.1}, {}, 시드 {}",
+        updates,
+        app.world
+            .resource::<PlayTime>()
+            .0,
+        *app.world
+            .resource::<GameClock>(),
+        app.world
+            .resource::<SimRng>()
+            .seed()
+    );
+    let mut query = app
+        .world
+        .query::<(Entity, &Name, &Person, Option<&Worker>, Option<&Wallet>)>();
+    for (entity, name, person, worker, wallet) in query.iter(&app.world) {
+        let salary = worker
+            .map(|worker| {
+                worker
+                    .salary
+                    .in_man_won()
+            })
+            .unwrap_or_else(|| "-".to_string());
+        let balance = wallet
+            .map(|wallet| wallet.balance.to_won_string())
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "{}: 체력 {} / 정신력 {} / 사망 {} / 연봉 {} / 지갑 {}",
+            name.as_str(),
+            person.hp,
+            person.san,
+            person.is_dead,
+            salary,
+            balance
+        );
+        if let Some(needs) = app
+            .world
+            .get::<Needs>(entity)
+        {
+            println!("  {}", needs);
+        }
+        if let Some(brain) = app
+            .world
+            .get::<NpcBrain>(entity)
+        {
+            let position = app
+                .world
+                .get::<Transform>(entity)
+                .map(|transform| transform.translation)
+                .unwrap_or_default();
+            println!(
+                "  행동 {} (일정 {}) / 위치 ({:.0}, {:.0}) / 도착 {}",
+                brain.activity, brain.scheduled, position.x, position.y, brain.arrived
+            );
+        }
+        if let (Some(task_list), Some(performance)) = (
+            app.world
+                .get::<TaskList>(entity),
+            app.world
+                .get::<Performance>(entity),
+        ) {
+            println!(
+                "  업무 {}건 / 완료 {} / 놓침 {}",
+                task_list
+                    .tasks
+                    .len(),
+                performance.completed,
+                performance.missed
+            );
+        }
+        if let (Some(rank), Some(record)) = (
+            app.world
+                .get::<Rank>(entity),
+            app.world
+                .get::<CareerRecord>(entity),
+        ) {
+            let last_grade = record
+                .last_review()
+                .map(|review| review.grade.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            let job_family = app
+                .world
+                .get::<JobFamily>(entity)
+                .map(|job_family| job_family.name())
+                .unwrap_or("-");
+            println!(
+                "  직급 {} / 직군 {} / 평가 {}회, 최근 {} / 해고 {}",
+                rank,
+                job_family,
+                record
+                    .reviews
+                    .len(),
+                last_grade,
+                app.world
+                    .get::<Fired>(entity)
+                    .is_some()
+            );
+        }
+    }
+
+    // 재생 결과가 기록과 다르면 실패로 종료
+    if let Some(outcome) = app
+        .world
+        .get_resource::<ReplayOutcome>()
+    {
+        match &outcome.differences {
+            None => println!("[headless] 재생 끝: {} 틱 (비교할 최종 상태 없음)", outcome.ticks),
+            Some(differences) if differences.is_empty() => {
+                println!("[headless] 재생 통과: {} 틱", outcome.ticks)
+            }
+            Some(differences) => {
+                println!("[headless] 재생 실패: {} 틱", outcome.ticks);
+                for difference in differences.iter() {
+                    println!("  {}", difference);
+                }
+            }
+        }
+        if !outcome.passed() {
+            std::process::exit(1);
+        }
+    } else if replay_ticks.is_some() {
+        println!("[headless] 재생 실패: 기록된 틱 수만큼 진행하지 못함");
+        std::process::exit(1);
+    }
+}
+
 fn sprite_setup(mut commands: Commands) {
     commands.spawn(Camera2dBundle::default());
 }
 
-fn add_system_entity(mut commands: Commands) {
+// 창이 없어도 대화 선택지와 클릭 위치를 재생할 수 있도록 화면에 그리지 않는 UI 상태만 배치
+fn add_headless_ui(mut commands: Commands) {
     commands.spawn((
-        Name::new("InteractTarget"),
-        InteractionTarget {
-            is_interactable: false,
-            target: Entity::PLACEHOLDER,
-            target_transform: Transform::from_xyz(0.0, 0.0, 0.0),
-            interaction_type: InteractionType::Invalid,
+        Name::new("ChoiceUI"),
+        ChoiceUI {
+            prompt: String::new(),
+            choices: Vec::new(),
+            selected: 0,
+            is_visible: false,
+            tranform: Transform::default(),
         },
     ));
+    commands.spawn(MouseInput::default());
+}
+
+fn add_hovered_entity(mut commands: Commands) {
     commands.spawn((
         Name::new("CurrentHovered"),
         CurrentHovered {
+            entity: None,
             selectable: None,
         },
     ));
 }
 
-fn add_player(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-) {
-    let shape = Mesh2dHandle(meshes.add(Circle { radius: 20.0 }));
-    let color = Color::hsl(0.0, 0.95, 0.7);
+fn add_player(mut commands: Commands) {
     commands.spawn((
         Player,
         Person::default(),
         Name::new("Player"),
         Worker::default(),
-        MaterialMesh2dBundle {
-            mesh: shape,
-            material: materials.add(color),
-            transform: Transform::from_xyz(0.0, 0.0, 0.0),
-            ..default()
-        },
+        SpatialBundle::from_transform(Transform::from_xyz(0.0, 0.0, 0.0)),
     ));
 }
 
-fn add_person(
-    name: &str,
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-) {
-    let shape = Mesh2dHandle(meshes.add(Circle { radius: 20.0 }));
-    let color = Color::hsl(40.0, 0.35, 0.7);
+fn add_person(name: &str, transform: Transform, worker: Worker, commands: &mut Commands) -> Entity {
+    commands
+        .spawn((
+            Person::default(),
+            Name::new(name.to_string()),
+            worker,
+            Interactable::coworker(),
+            BoardEntity,
+            SpatialBundle::from_transform(transform),
+            MouseSelectable {
+                display_name: name.to_string(),
+                select_rect: Rect {
+                    min: Vec2::new(-5.0, -5.0),
+                    max: Vec2::new(5.0, 5.0),
+                },
+            },
+        ))
+        .id()
+}
+
+// 위치와 연봉은 그 사람의 난수 스트림으로 뽑아서 다른 사람이 추가되어도 바뀌지 않음
+fn add_random_person(name: &str, sim_rng: &SimRng, commands: &mut Commands) {
+    let mut rng = sim_rng.fork(name);
     let random_transform = Transform::from_xyz(
-        rand::random::<f32>() * 800.0 - 400.0,
-        rand::random::<f32>() * 800.0 - 400.0,
+        rng.gen::<f32>() * 800.0 - 400.0,
+        rng.gen::<f32>() * 800.0 - 400.0,
         0.0,
     );
     let random_salary = Salary {
-        amount: rand::random::<f32>() * 3000.0,
-        currency: "KRW".to_string(),
+        amount: Money::man_won(rng.gen_range(0..3000)),
     };
-    commands.spawn((
-        Person::default(),
-        Name::new(name.to_string()),
-        Worker {
-            salary: random_salary,
-        },
-        Interactable {
-            interaction_type: InteractionType::SalaryMan,
-        },
-        MaterialMesh2dBundle {
-            mesh: shape,
-            material: materials.add(color),
-            transform: random_transform,
-            ..default()
-        },
-        MouseSelectable {
-            display_name: name.to_string(),
-            select_rect: Rect {
-                min: Vec2::new(-5.0, -5.0),
-                max: Vec2::new(5.0, 5.0),
+    let worker = Worker {
+        salary: random_salary,
+        ..default()
+    };
+    let person = add_person(name, random_transform, worker, commands);
+    commands
+        .entity(person)
+        .insert(rng);
+}
+
+// 타일맵의 벽 타일마다 움직이지 않는 충돌체를 배치
+fn add_walls(commands: &mut Commands, tile_map: &TileMap) {
+    let half_size = Vec2::splat(tile_map.tile_size() / 2.0);
+    for coordinates in tile_map.tiles_of(Tile::Wall) {
+        commands.spawn((
+            Wall,
+            Name::new("Wall"),
+            coordinates,
+            Collider::new(Rectangle { half_size }),
+            StaticCollider,
+            BoardEntity,
+            SpatialBundle::from_transform(tile_map.tile_to_transform(coordinates)),
+        ));
+    }
+}
+
+// 타일맵의 책상 타일마다 책상을 배치
+fn add_desk(commands: &mut Commands, tile_map: &TileMap) {
+    for coordinates in tile_map.tiles_of(Tile::Desk) {
+        commands.spawn((
+            Desk,
+            Interactable::desk(),
+            Name::new("Desk"),
+            coordinates,
+            BoardEntity,
+            SpatialBundle::from_transform(tile_map.tile_to_transform(coordinates)),
+        ));
+    }
+}
+
+// 배치도에 적힌 물건을 배치. 행동이 적혀 있지 않으면 종류마다 정해진 행동을 씀
+fn add_objects(commands: &mut Commands, tile_map: &TileMap, objects: &[ObjectSpawn]) {
+    for object in objects.iter() {
+        let interactable = if object
+            .actions
+            .is_empty()
+        {
+            Interactable::for_object(&object.kind)
+        } else {
+            match ron::from_str::<Vec<InteractionAction>>(&object.actions) {
+                Ok(actions) => Some(Interactable { actions }),
+                Err(error) => {
+                    warn!("Could not parse actions of {} in layout: {}", object.kind, error);
+                    continue;
+                }
+            }
+        };
+        let Some(interactable) = interactable else {
+            warn!("Unknown object kind in layout: {}", object.kind);
+            continue;
+        };
+        let name = if object
+            .name
+            .is_empty()
+        {
+            object
+                .kind
+                .clone()
+        } else {
+            object
+                .name
+                .clone()
+        };
+        commands.spawn((
+            interactable,
+            Name::new(name.clone()),
+            object.position,
+            BoardEntity,
+            SpatialBundle::from_transform(tile_map.tile_to_transform(object.position)),
+            MouseSelectable {
+                display_name: name,
+                select_rect: Rect {
+                    min: Vec2::new(-5.0, -5.0),
+                    max: Vec2::new(5.0, 5.0),
+                },
             },
-        },
-    ));
+        ));
+    }
+}
+
+// 배치도에 적힌 동료들을 배치하고, 배치도가 없으면 임의의 위치에 배치
+fn add_people(commands: &mut Commands, tile_map: &TileMap, layout: Option<&OfficeLayout>, sim_rng: &SimRng) {
+    let Some(layout) = layout else {
+        add_random_person("Alice", sim_rng, commands);
+        add_random_person("Bob", sim_rng, commands);
+        add_random_person("Charlie", sim_rng, commands);
+        return;
+    };
+
+    for npc in layout.npcs.iter() {
+        let worker = Worker {
+            salary: Salary {
+                amount: Money::man_won(npc.salary as i64),
+            },
+            role: npc.role.clone(),
+        };
+        let person = add_person(&npc.name, tile_map.tile_to_transform(npc.position), worker, commands);
+        commands
+            .entity(person)
+            .insert(Rank::from_name(&npc.rank));
+        if npc.manager {
+            commands
+                .entity(person)
+                .insert(Manager);
+        }
+    }
 }
 
-fn add_desk(
+// 타일맵이 생성되거나 배치도가 바뀔 때마다 벽, 책상, 물건을 다시 배치
+// 동료는 처음 한 번만 배치해서 배치도를 고쳐도 상태가 초기화되지 않음
+fn spawn_office(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
+    tile_map: Res<TileMap>,
+    board_layout: Option<Res<BoardLayout>>,
+    layouts: Res<Assets<OfficeLayout>>,
+    sim_rng: Res<SimRng>,
+    q_board_entities: Query<Entity, (With<BoardEntity>, Without<Person>)>,
+    q_coworkers: Query<(), (With<Person>, Without<Player>)>,
 ) {
-    let shape = Mesh2dHandle(meshes.add(Rectangle::new(50.0, 100.0)));
-    let color = Color::hsl(0.0, 0.0, 0.5);
-    commands.spawn((
-        Desk,
-        Interactable {
-            interaction_type: InteractionType::Work,
-        },
-        Name::new("Desk"),
-        MaterialMesh2dBundle {
-            mesh: shape,
-            material: materials.add(color),
-            transform: Transform::from_xyz(0.0, -200.0, 0.0),
-            ..default()
-        },
-    ));
+    for entity in q_board_entities.iter() {
+        commands
+            .entity(entity)
+            .despawn_recursive();
+    }
+
+    let layout = board_layout.and_then(|board_layout| layouts.get(&board_layout.0));
+    add_walls(&mut commands, &tile_map);
+    add_desk(&mut commands, &tile_map);
+    if let Some(layout) = layout {
+        add_objects(&mut commands, &tile_map, &layout.objects);
+    }
+    if q_coworkers.is_empty() {
+        add_people(&mut commands, &tile_map, layout, &sim_rng);
+    }
 }
 
-fn add_people(
+/// 새로 배치된 물건 (사람과 책상이 아닌 상호작용 대상)
+type AddedObjectFilter = (Added<Interactable>, Without<Person>, Without<Desk>);
+
+// 시뮬레이션이 생성한 엔티티에 메시와 재질을 붙여서 화면에 그림
+fn add_sprites(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    q_people: Query<(Entity, &Transform, Has<Player>), Added<Person>>,
+    q_desks: Query<Entity, Added<Desk>>,
+    q_walls: Query<(Entity, &Collider), Added<Wall>>,
+    q_objects: Query<Entity, AddedObjectFilter>,
 ) {
-    add_person("Alice", &mut commands, &mut meshes, &mut materials);
-    add_person("Bob", &mut commands, &mut meshes, &mut materials);
-    add_person("Charlie", &mut commands, &mut meshes, &mut materials);
+    for (entity, transform, is_player) in q_people.iter() {
+        let shape = Mesh2dHandle(meshes.add(PERSON_SHAPE));
+        let color = if is_player {
+            Color::hsl(0.0, 0.95, 0.7)
+        } else {
+            Color::hsl(40.0, 0.35, 0.7)
+        };
+        commands
+            .entity(entity)
+            .insert((shape, materials.add(color), TransformInterpolation::new(transform.translation)));
+    }
+
+    for entity in q_desks.iter() {
+        let shape = Mesh2dHandle(meshes.add(DESK_SHAPE));
+        let color = Color::hsl(0.0, 0.0, 0.5);
+        commands
+            .entity(entity)
+            .insert((shape, materials.add(color)));
+    }
+
+    for entity in q_objects.iter() {
+        let shape = Mesh2dHandle(meshes.add(OBJECT_SHAPE));
+        let color = Color::hsl(200.0, 0.4, 0.6);
+        commands
+            .entity(entity)
+            .insert((shape, materials.add(color)));
+    }
+
+    for (entity, collider) in q_walls.iter() {
+        let ColliderShape::Rect { half_size } = collider.shape else {
+            continue;
+        };
+        let shape = Mesh2dHandle(meshes.add(Rectangle { half_size }));
+        let color = Color::hsl(0.0, 0.0, 0.25);
+        commands
+            .entity(entity)
+            .insert((shape, materials.add(color)));
+    }
 }
 
 #[derive(Resource)]
@@ -171,33 +780,170 @@ fn greet_people(time: Res<Time>, mut timer: ResMut<GreetTimer>, query: Query<&Na
     }
 }
 
-fn update_people(mut query: Query<&mut Person>) {
-    for mut person in &mut query {
-        if person.hp <= 0 || person.san <= 0 {
+fn update_play_time(time: Res<Time>, mut play_time: ResMut<PlayTime>) {
+    play_time.0 += time.delta_seconds();
+}
+
+// 체력, 정신력이 바닥나거나 굶주림과 과로로 쓰러지면 사망. 스트레스가 한계인데 의욕이 없으면 번아웃
+fn update_people(
+    mut commands: Commands,
+    config: Res<NeedsConfig>,
+    mut query: Query<(Entity, &mut Person, Option<&Needs>, Has<Burnout>)>,
+) {
+    for (entity, mut person, needs, burned_out) in &mut query {
+        let collapsed = needs.is_some_and(|needs| needs.is_collapsed());
+        if person.hp <= 0 || person.san <= 0 || collapsed {
             person.is_dead = true;
         }
+
+        let Some(needs) = needs else {
+            continue;
+        };
+        if !burned_out && needs.is_burned_out() {
+            commands
+                .entity(entity)
+                .insert(Burnout);
+        } else if burned_out && needs.stress < config.burnout_recovery_stress {
+            commands
+                .entity(entity)
+                .remove::<Burnout>();
+        }
     }
 }
 
+/// HUD에 표시하는 플레이어의 상태
+#[derive(QueryData)]
+struct PlayerStatus {
+    person: &'static Person,
+    wallet: Option<&'static Wallet>,
+    needs: Option<&'static Needs>,
+    burned_out: Has<Burnout>,
+    task_list: Option<&'static TaskList>,
+    performance: Option<&'static Performance>,
+    working: Has<Working>,
+    rank: Option<&'static Rank>,
+    job_family: Option<&'static JobFamily>,
+    career: Option<&'static CareerRecord>,
+    fired: Has<Fired>,
+    interacting: Option<&'static Interacting>,
+}
+
 // 캐릭터의 상태를 표시하는 HUD
 fn update_hud(
     mut huds: Query<&mut Text, With<StatusHUD>>,
-    player_query: Query<&Person, With<Player>>,
-    time: Res<Time>,
+    player_query: Query<PlayerStatus, With<Player>>,
+    clock: Res<GameClock>,
+    (save_slot, sim_rng): (Res<SaveSlot>, Res<SimRng>),
+    actions: UiActionInput,
     q_mouse_inputs: Query<&MouseInput>,
     q_current_hovered: Query<&CurrentHovered>,
 ) {
     let mut _text = huds.single_mut();
 
     for player in player_query.iter() {
-        _text.sections[0].value = format!("체력: {}\n정신력: {}\n", player.hp, player.san);
+        _text.sections[0].value = format!("체력: {}\n정신력: {}\n", player.person.hp, player.person.san);
+        if let Some(needs) = player.needs {
+            _text.sections[0]
+                .value
+                .push_str(&format!(
+                    "배고픔: {:.0}\n피로: {:.0}\n스트레스: {:.0}\n의욕: {:.0}\n사교: {:.0}\n",
+                    needs.hunger, needs.fatigue, needs.stress, needs.motivation, needs.social
+                ));
+        }
+        if let (Some(rank), Some(job_family)) = (player.rank, player.job_family) {
+            let last_grade = player
+                .career
+                .and_then(|career| career.last_review())
+                .map(|review| review.grade.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            _text.sections[0]
+                .value
+                .push_str(&format!("직급: {} ({}) / 최근 평가: {}\n", rank, job_family, last_grade));
+        }
+        if player.fired {
+            _text.sections[0]
+                .value
+                .push_str("해고됨!\n");
+        }
+        if player.burned_out {
+            _text.sections[0]
+                .value
+                .push_str("번아웃!\n");
+        }
+        if let Some(wallet) = player.wallet {
+            _text.sections[0]
+                .value
+                .push_str(&format!(
+                    "지갑: {} ({})\n",
+                    wallet.balance,
+                    actions.hint("{TogglePayslip} 명세서")
+                ));
+        }
+        if let (Some(task_list), Some(performance)) = (player.task_list, player.performance) {
+            let state = if player.working {
+                "작업 중".to_string()
+            } else {
+                actions.hint("책상에서 {Interact}")
+            };
+            match task_list.current() {
+                Some(task) => _text.sections[0]
+                    .value
+                    .push_str(&format!(
+                        "업무: {} {:.0}% (마감 {}, {})\n",
+                        task.title,
+                        task.ratio() * 100.0,
+                        task.deadline(),
+                        state
+                    )),
+                None => _text.sections[0]
+                    .value
+                    .push_str("업무: 없음\n"),
+            }
+            _text.sections[0]
+                .value
+                .push_str(&format!(
+                    "남은 업무 {}건 / 완료 {} / 놓침 {}\n",
+                    task_list
+                        .tasks
+                        .len(),
+                    performance.completed,
+                    performance.missed
+                ));
+        }
+        if let Some(interacting) = player.interacting {
+            _text.sections[0]
+                .value
+                .push_str(&format!(
+                    "{} {:.0}% ({})\n",
+                    interacting.label,
+                    interacting.ratio() * 100.0,
+                    actions.hint("{Interact} 취소")
+                ));
+        }
         _text.sections[0]
             .value
-            .push_str(&format!(
-                "지난 시간: {:.1}\n",
-                time.elapsed_seconds() as f32
-            ));
+            .push_str(&format!("{}\n", *clock));
     }
+    let clock_state = if clock.paused {
+        "일시정지"
+    } else if clock.is_work_hours() {
+        "근무 중"
+    } else {
+        "근무 외"
+    };
+    _text.sections[0]
+        .value
+        .push_str(&format!("{} (x{})\n", clock_state, clock.time_scale));
+    _text.sections[0]
+        .value
+        .push_str(&format!(
+            "저장 슬롯: {} ({})\n",
+            *save_slot,
+            actions.hint("{SelectSlot1}/{SelectSlot2}/{SelectSlot3} 슬롯 / {SaveGame} 저장 / {LoadGame} 불러오기 / {OpenMenu} 키 설정")
+        ));
+    _text.sections[0]
+        .value
+        .push_str(&format!("시드: {}\n", sim_rng.seed()));
 
     let q_mouse_input = q_mouse_inputs.single();
     _text.sections[0]
@@ -244,20 +990,101 @@ struct GUISet;
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 struct InputSet;
 
-pub struct HelloPlugin;
+/// 창 없이도 돌아가는 게임 로직 (사람, 책상, 플레이어 상호작용)
+pub struct SimulationPlugin;
 
-impl Plugin for HelloPlugin {
+impl Plugin for SimulationPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(GreetTimer(Timer::from_seconds(2.0, TimerMode::Repeating)))
+        // 플러그인 튜플은 15개까지라 둘로 나눔
+        app.add_plugins((
+            (
+                ActionsPlugin,
+                BoardPlugin,
+                CareerPlugin,
+                ClockPlugin,
+                DialoguePlugin,
+                IncidentPlugin,
+                InteractionPlugin,
+                NavigationPlugin,
+                NeedsPlugin,
+                NpcPlugin,
+            ),
+            (
+                PayrollPlugin,
+                PhysicsPlugin,
+                RelationshipPlugin,
+                ReplayPlugin,
+                RngPlugin,
+                RumorPlugin,
+                SavePlugin,
+                TaskPlugin,
+                TickPlugin,
+            ),
+        ))
+            .init_resource::<PlayTime>()
+            .init_resource::<ExchangeRates>()
+            .insert_resource(GreetTimer(Timer::from_seconds(2.0, TimerMode::Repeating)))
+            .add_systems(Startup, add_player)
+            .add_systems(
+                FixedUpdate,
+                spawn_office
+                    .run_if(resource_exists_and_changed::<TileMap>)
+                    .before(WorldUpdateSet),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    greet_people,
+                    update_play_time,
+                    update_people,
+                    (
+                        player::add_path_follower,
+                        player::player_movement,
+                        player::click_to_move,
+                    )
+                        .chain()
+                        .before(NavigationSet),
+                    (
+                        player::update_facing,
+                        player::player_check_collision,
+                        player::cycle_interaction_target,
+                    )
+                        .chain()
+                        .before(player::interact),
+                    // 선택지를 고른 버튼으로 같은 틱에 다시 말을 걸지 않도록 선택지부터 처리
+                    (gui::choice_input, player::interact).chain(),
+                    player::dead_player,
+                )
+                    .in_set(WorldUpdateSet),
+            )
+            // 배치도가 로드되기 전에는 시간이 흐르지 않고, 시계가 멈추면 월드도 멈춤
+            .configure_sets(
+                FixedUpdate,
+                (
+                    ClockSet
+                        .before(WorldUpdateSet)
+                        .run_if(resource_exists::<TileMap>),
+                    WorldUpdateSet
+                        .run_if(resource_exists::<TileMap>)
+                        .run_if(clock::clock_running),
+                ),
+            );
+    }
+}
+
+/// 화면 출력과 마우스 입력 (GUI, 마우스 오버 힌트, 스프라이트)
+pub struct PresentationPlugin;
+
+impl Plugin for PresentationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InterpolationPlugin)
             .add_systems(
                 Startup,
                 (
-                    add_system_entity,
-                    add_player,
-                    add_people,
-                    add_desk,
+                    add_hovered_entity,
                     gui::setup_font,
                     gui::add_gui,
+                    gui::add_task_progress_bar,
                     mouse_event::add_mouse_input,
                 )
                     .chain(),
@@ -265,26 +1092,95 @@ impl Plugin for HelloPlugin {
             .add_systems(
                 Update,
                 (
+                    add_sprites,
                     (
-                        greet_people,
-                        update_people,
-                        player::player_check_collision,
-                        player::player_movement,
-                        player::interact,
-                        player::dead_player,
+                        gui::update_pop_up,
+                        gui::update_incident_pop_up,
+                        gui::update_choice_ui,
+                        gui::update_payslip_ui,
+                        (gui::rebinding_input, gui::update_rebinding_ui).chain(),
+                        gui::update_task_progress_bar,
+                        gui::update_interaction_hint,
+                        gui::update_interaction_wheel,
+                        update_hud,
                     )
-                        .in_set(WorldUpdateSet),
-                    (gui::update_pop_up, gui::update_choice_ui, update_hud).in_set(GUISet),
-                    (mouse_event::listen_mouse_input, mouse_event::mouse_event).in_set(InputSet),
+                        .in_set(GUISet),
+                    (
+                        // 재생하는 동안은 기록된 커서 위치를 씀
+                        mouse_event::listen_mouse_input.run_if(not(resource_exists::<InputReplay>)),
+                        mouse_event::mouse_event,
+                        mouse_event::gamepad_focus_hint,
+                    )
+                        .chain()
+                        .in_set(InputSet),
                 ),
             )
             .configure_sets(
                 Update,
-                (
-                    WorldUpdateSet,
-                    GUISet.after(WorldUpdateSet),
-                    InputSet.after(GUISet),
-                ),
+                InputSet.after(GUISet),
             );
     }
 }
+
+pub struct HelloPlugin;
+
+impl Plugin for HelloPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((SimulationPlugin, PresentationPlugin));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 기록 파일을 main과 같은 방식으로 창 없이 재생
+    fn replay_app(recording: InputRecording) -> App {
+        let mut app = App::new();
+        let tick_rate = TickRate(recording.tick_rate);
+        app.insert_resource(GameClock {
+            time_scale: recording.time_scale,
+            ..default()
+        })
+        .insert_resource(tick_rate)
+        .insert_resource(SimRng::new(recording.seed))
+        .insert_resource(
+            recording
+                .bindings
+                .clone(),
+        )
+        .insert_resource(BoardOptions {
+            layout_path: Some(
+                recording
+                    .layout_path
+                    .clone(),
+            ),
+            ..default()
+        });
+        app.insert_resource(InputReplay::new(recording));
+        add_headless_plugins(&mut app, tick_rate);
+        register_types(&mut app);
+        finish_plugins(&mut app);
+        app
+    }
+
+    #[test]
+    fn talk_to_coworker_replay_passes() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("replays/talk_to_coworker.replay.ron");
+        let recording = InputRecording::load(&path).unwrap();
+        let ticks = recording.ticks;
+        let mut app = replay_app(recording);
+
+        run_replay(&mut app, ticks);
+
+        let outcome = app
+            .world
+            .get_resource::<ReplayOutcome>()
+            .expect("replay did not finish");
+        assert_eq!(outcome.ticks, ticks);
+        assert!(outcome
+            .differences
+            .is_some());
+        assert!(outcome.passed(), "{:?}", outcome.differences);
+    }
+}
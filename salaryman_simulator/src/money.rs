@@ -0,0 +1,397 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+use bevy::prelude::*;
+use thiserror::Error;
+
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Currency {
+    #[default]
+    KRW,
+    USD,
+    JPY,
+    EUR,
+}
+
+impl Currency {
+    /// 보조 단위 자릿수 (USD는 센트까지 2자리, 원은 0자리)
+    pub const fn minor_digits(&self) -> u32 {
+        match self {
+            Currency::KRW | Currency::JPY => 0,
+            Currency::USD | Currency::EUR => 2,
+        }
+    }
+
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Currency::KRW => "KRW",
+            Currency::USD => "USD",
+            Currency::JPY => "JPY",
+            Currency::EUR => "EUR",
+        }
+    }
+
+    const fn minor_per_major(&self) -> i64 {
+        10_i64.pow(self.minor_digits())
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum MoneyError {
+    #[error("Cannot mix {0} and {1}")]
+    CurrencyMismatch(Currency, Currency),
+    #[error("Money amount overflowed")]
+    Overflow,
+    #[error("Money divided by zero")]
+    DivisionByZero,
+    #[error("No exchange rate for {0}")]
+    UnknownRate(Currency),
+}
+
+/// 보조 단위 정수로 저장하는 금액. 원화는 1원 단위입니다.
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    /// 보조 단위 금액 (원, 센트 등)
+    pub amount: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub const fn new(amount: i64, currency: Currency) -> Self {
+        Money { amount, currency }
+    }
+
+    pub const fn zero(currency: Currency) -> Self {
+        Money::new(0, currency)
+    }
+
+    pub const fn won(amount: i64) -> Self {
+        Money::new(amount, Currency::KRW)
+    }
+
+    pub const fn man_won(amount: i64) -> Self {
+        Money::won(amount * MAN as i64)
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch(self.currency, other.currency));
+        }
+        Ok(())
+    }
+
+    pub fn checked_add(self, other: Money) -> Result<Money, MoneyError> {
+        self.same_currency(&other)?;
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    pub fn checked_sub(self, other: Money) -> Result<Money, MoneyError> {
+        self.same_currency(&other)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Result<Money, MoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(factor)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// 나머지는 버립니다.
+    pub fn checked_div(self, divisor: i64) -> Result<Money, MoneyError> {
+        if divisor == 0 {
+            return Err(MoneyError::DivisionByZero);
+        }
+        let amount = self
+            .amount
+            .checked_div(divisor)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// 세율처럼 비율을 곱하고 보조 단위에서 반올림
+    pub fn mul_rate(self, rate: f64) -> Result<Money, MoneyError> {
+        let amount = (self.amount as f64 * rate).round();
+        if !amount.is_finite() || amount < i64::MIN as f64 || amount > i64::MAX as f64 {
+            return Err(MoneyError::Overflow);
+        }
+        Ok(Money::new(amount as i64, self.currency))
+    }
+
+    /// `unit`의 배수로 반올림. 연봉을 만원 단위로 맞출 때 씁니다.
+    pub fn round_to(self, unit: Money) -> Result<Money, MoneyError> {
+        self.same_currency(&unit)?;
+        if unit.amount <= 0 {
+            return Err(MoneyError::DivisionByZero);
+        }
+        let half = unit.amount / 2;
+        let units = self
+            .amount
+            .checked_add(if self.amount < 0 { -half } else { half })
+            .ok_or(MoneyError::Overflow)?
+            / unit.amount;
+        unit.checked_mul(units)
+    }
+
+    pub fn min(self, other: Money) -> Money {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// 다른 통화로 환전. 보조 단위에서 반올림합니다.
+    pub fn convert(self, to: Currency, rates: &ExchangeRates) -> Result<Money, MoneyError> {
+        if self.currency == to {
+            return Ok(self);
+        }
+        let from_rate = rates.rate(self.currency)?;
+        let to_rate = rates.rate(to)?;
+        let major = self.amount as f64 / self.currency.minor_per_major() as f64;
+        let converted = major * from_rate / to_rate;
+        Money::new(1, to).mul_rate(converted * to.minor_per_major() as f64)
+    }
+
+    /// "1,234,567원"
+    pub fn to_won_string(&self) -> String {
+        if self.currency != Currency::KRW {
+            return self.to_string();
+        }
+        format!("{}{}원", sign(self.amount), group_thousands(self.amount.unsigned_abs()))
+    }
+
+    /// "1,234만원", 만원 아래가 있으면 "1,234.5678만원"
+    pub fn to_man_won_string(&self) -> String {
+        if self.currency != Currency::KRW {
+            return self.to_string();
+        }
+        let abs = self.amount.unsigned_abs();
+        let (man, rest) = (abs / MAN, abs % MAN);
+        let fraction = if rest == 0 {
+            String::new()
+        } else {
+            format!(".{:04}", rest)
+                .trim_end_matches('0')
+                .to_string()
+        };
+        format!("{}{}{}만원", sign(self.amount), group_thousands(man), fraction)
+    }
+}
+
+/// 원화는 억/만 단위로 끊어서 "1억 2,345만 6,789원", 다른 통화는 "1,234.56 USD"
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abs = self.amount.unsigned_abs();
+        if self.currency != Currency::KRW {
+            let minor_per_major = self
+                .currency
+                .minor_per_major() as u64;
+            let digits = self
+                .currency
+                .minor_digits() as usize;
+            write!(f, "{}{}", sign(self.amount), group_thousands(abs / minor_per_major))?;
+            if digits > 0 {
+                write!(f, ".{:0digits$}", abs % minor_per_major, digits = digits)?;
+            }
+            return write!(f, " {}", self.currency);
+        }
+
+        if abs == 0 {
+            return write!(f, "0원");
+        }
+        let units = [
+            (abs / JO, "조"),
+            (abs / EOK % MAN, "억"),
+            (abs / MAN % MAN, "만"),
+            (abs % MAN, ""),
+        ];
+        let parts: Vec<String> = units
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| format!("{}{}", group_thousands(*value), unit))
+            .collect();
+        write!(f, "{}{}원", sign(self.amount), parts.join(" "))
+    }
+}
+
+const MAN: u64 = 10_000;
+const EOK: u64 = MAN * MAN;
+const JO: u64 = EOK * MAN;
+
+fn sign(amount: i64) -> &'static str {
+    if amount < 0 {
+        "-"
+    } else {
+        ""
+    }
+}
+
+/// 세 자리마다 쉼표를 넣음
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits
+        .chars()
+        .enumerate()
+    {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// 통화가 다르면 비교할 수 없습니다.
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.amount
+            .partial_cmp(&other.amount)
+    }
+}
+
+/// 통화별 환율 (1 단위당 원화)
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct ExchangeRates {
+    pub won_per_unit: HashMap<Currency, f64>,
+}
+
+impl Default for ExchangeRates {
+    fn default() -> Self {
+        ExchangeRates {
+            won_per_unit: HashMap::from([
+                (Currency::KRW, 1.0),
+                (Currency::USD, 1350.0),
+                (Currency::JPY, 9.0),
+                (Currency::EUR, 1450.0),
+            ]),
+        }
+    }
+}
+
+impl ExchangeRates {
+    pub fn rate(&self, currency: Currency) -> Result<f64, MoneyError> {
+        self.won_per_unit
+            .get(&currency)
+            .copied()
+            .ok_or(MoneyError::UnknownRate(currency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn won_string_groups_thousands() {
+        assert_eq!(Money::won(0).to_won_string(), "0원");
+        assert_eq!(Money::won(999).to_won_string(), "999원");
+        assert_eq!(Money::won(1_000).to_won_string(), "1,000원");
+        assert_eq!(Money::won(1_234_567).to_won_string(), "1,234,567원");
+        assert_eq!(Money::won(-1_234_567).to_won_string(), "-1,234,567원");
+    }
+
+    #[test]
+    fn man_won_string_keeps_remainder() {
+        assert_eq!(Money::man_won(1_234).to_man_won_string(), "1,234만원");
+        assert_eq!(Money::won(12_345_678).to_man_won_string(), "1,234.5678만원");
+        assert_eq!(Money::won(12_345_000).to_man_won_string(), "1,234.5만원");
+        assert_eq!(Money::won(100).to_man_won_string(), "0.01만원");
+        assert_eq!(Money::won(-50_000).to_man_won_string(), "-5만원");
+    }
+
+    #[test]
+    fn display_splits_won_into_eok_and_man() {
+        assert_eq!(Money::won(0).to_string(), "0원");
+        assert_eq!(Money::won(6_789).to_string(), "6,789원");
+        assert_eq!(Money::man_won(1).to_string(), "1만원");
+        assert_eq!(Money::won(123_456_789).to_string(), "1억 2,345만 6,789원");
+        assert_eq!(Money::won(100_000_500).to_string(), "1억 500원");
+        assert_eq!(Money::won(-100_000_000).to_string(), "-1억원");
+        assert_eq!(Money::won(3_000_000_000_000).to_string(), "3조원");
+    }
+
+    #[test]
+    fn display_other_currencies_with_minor_digits() {
+        assert_eq!(Money::new(123_456, Currency::USD).to_string(), "1,234.56 USD");
+        assert_eq!(Money::new(-5, Currency::EUR).to_string(), "-0.05 EUR");
+        assert_eq!(Money::new(1_500, Currency::JPY).to_string(), "1,500 JPY");
+        assert_eq!(Money::new(100, Currency::USD).to_won_string(), "1.00 USD");
+    }
+
+    #[test]
+    fn checked_arithmetic_reports_overflow() {
+        assert_eq!(Money::won(1).checked_add(Money::won(2)), Ok(Money::won(3)));
+        assert_eq!(Money::won(1).checked_sub(Money::won(2)), Ok(Money::won(-1)));
+        assert_eq!(Money::won(i64::MAX).checked_add(Money::won(1)), Err(MoneyError::Overflow));
+        assert_eq!(Money::won(i64::MIN).checked_sub(Money::won(1)), Err(MoneyError::Overflow));
+        assert_eq!(Money::won(i64::MAX).checked_mul(2), Err(MoneyError::Overflow));
+        assert_eq!(Money::won(i64::MIN).checked_div(-1), Err(MoneyError::Overflow));
+        assert_eq!(Money::won(10).checked_div(0), Err(MoneyError::DivisionByZero));
+        assert_eq!(Money::won(i64::MAX).mul_rate(2.0), Err(MoneyError::Overflow));
+        assert_eq!(Money::won(1).mul_rate(f64::NAN), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn mixing_currencies_is_an_error() {
+        let won = Money::won(1_000);
+        let dollars = Money::new(1_000, Currency::USD);
+        let mismatch = Err(MoneyError::CurrencyMismatch(Currency::KRW, Currency::USD));
+        assert_eq!(won.checked_add(dollars), mismatch);
+        assert_eq!(won.checked_sub(dollars), mismatch);
+        assert_eq!(won.round_to(dollars), mismatch);
+        assert_eq!(won.partial_cmp(&dollars), None);
+    }
+
+    #[test]
+    fn round_to_and_mul_rate_round_half_away_from_zero() {
+        assert_eq!(Money::won(12_345_678).round_to(Money::man_won(1)), Ok(Money::won(12_350_000)));
+        assert_eq!(Money::won(-12_344_999).round_to(Money::man_won(1)), Ok(Money::won(-12_340_000)));
+        assert_eq!(Money::won(1_000).round_to(Money::won(0)), Err(MoneyError::DivisionByZero));
+        assert_eq!(Money::won(1_000).mul_rate(0.0045), Ok(Money::won(5)));
+    }
+
+    #[test]
+    fn convert_between_currencies() {
+        let rates = ExchangeRates::default();
+        assert_eq!(Money::new(100, Currency::USD).convert(Currency::KRW, &rates), Ok(Money::won(1_350)));
+        assert_eq!(Money::won(13_500).convert(Currency::USD, &rates), Ok(Money::new(1_000, Currency::USD)));
+        let no_rates = ExchangeRates {
+            won_per_unit: HashMap::new(),
+        };
+        assert_eq!(
+            Money::won(1).convert(Currency::USD, &no_rates),
+            Err(MoneyError::UnknownRate(Currency::KRW))
+        );
+    }
+}
@@ -2,7 +2,9 @@ use bevy::prelude::*;
 use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
 use bevy::window::PrimaryWindow;
 
+use crate::actions::InputDevice;
 use crate::components::{CurrentHovered, MouseHoverHint, MouseInput, MouseSelectable};
+use crate::gui::components::{ChoiceItem, ChoiceUI};
 
 pub fn add_mouse_input(
     mut commands: Commands,
@@ -56,16 +58,17 @@ pub fn listen_mouse_input(
 
 pub fn mouse_event(
     q_mouse_inputs: Query<&MouseInput>,
-    q_selectables: Query<(&Transform, &MouseSelectable), Without<MouseHoverHint>>,
+    q_selectables: Query<(Entity, &Transform, &MouseSelectable), Without<MouseHoverHint>>,
     mut q_hint: Query<(&mut Transform, &mut Visibility), With<MouseHoverHint>>,
     mut q_current_hovered: Query<&mut CurrentHovered>,
 ) {
     let q_mouse_input = q_mouse_inputs.single();
 
     let mut target_transform = Transform::from_xyz(0.0, 0.0, 0.0);
+    let mut hovered_entity: Option<Entity> = None;
     let mut hovered_selectable: Option<&MouseSelectable> = None;
 
-    for (selectable_transform, selectable) in q_selectables.iter()
+    for (selectable_entity, selectable_transform, selectable) in q_selectables.iter()
     {
         let x = q_mouse_input
             .world_position
@@ -85,8 +88,8 @@ pub fn mouse_event(
             && y > selectable.select_rect.min.y * 8.0
             && y < selectable.select_rect.max.y * 8.0
         {
-            println!("Mouse is on the object!");
             target_transform = selectable_transform.clone();
+            hovered_entity = Some(selectable_entity);
             hovered_selectable = Some(selectable);
             break;
         }
@@ -94,6 +97,7 @@ pub fn mouse_event(
 
     for mut current_hovered in q_current_hovered.iter_mut()
     {
+        current_hovered.entity = hovered_entity;
         if let Some(target_selectable) = hovered_selectable {
             current_hovered
                 .selectable = Some(target_selectable.clone());
@@ -107,27 +111,7 @@ pub fn mouse_event(
     {
         if let Some(target_selectable) = hovered_selectable {
             *hint_visibility = Visibility::Visible;
-
-            // 위치를 선택 대상과 동일하게 업데이트
-            hint_transform
-                .translation
-                .x = target_transform
-                .translation
-                .x;
-            hint_transform
-                .translation
-                .y = target_transform
-                .translation
-                .y;
-
-            // 크기를 선택 대상과 동일하게 업데이트
-            hint_transform
-                .scale
-                .x = target_selectable.select_rect.width() * 0.1; // 화면 배율에 따라 조정해야겠지만 지금은 임시
-
-            hint_transform
-                .scale
-                .y = target_selectable.select_rect.height() * 0.1; // 화면 배율에 따라 조정해야겠지만 지금은 임시
+            place_hint(&mut hint_transform, &target_transform, target_selectable);
         }
         else {
             *hint_visibility = Visibility::Hidden;
@@ -135,3 +119,65 @@ pub fn mouse_event(
         }
     }
 }
+
+// 게임패드로 선택지를 고르는 동안에는 마우스 대신 강조된 선택지에 힌트를 표시
+pub fn gamepad_focus_hint(
+    device: Res<InputDevice>,
+    q_choices: Query<&ChoiceUI>,
+    q_items: Query<(Entity, &Transform, &ChoiceItem, &MouseSelectable), Without<MouseHoverHint>>,
+    mut q_hint: Query<(&mut Transform, &mut Visibility), With<MouseHoverHint>>,
+    mut q_current_hovered: Query<&mut CurrentHovered>,
+) {
+    if *device != InputDevice::Gamepad {
+        return;
+    }
+    let Some(choice) = q_choices
+        .iter()
+        .find(|choice| choice.is_visible)
+    else {
+        return;
+    };
+    let Some((focused_entity, focused_transform, _, focused_selectable)) = q_items
+        .iter()
+        .find(|(_, _, item, _)| item.index == choice.selected)
+    else {
+        return;
+    };
+
+    for mut current_hovered in q_current_hovered.iter_mut()
+    {
+        current_hovered.entity = Some(focused_entity);
+        current_hovered
+            .selectable = Some(focused_selectable.clone());
+    }
+
+    for (mut hint_transform, mut hint_visibility) in q_hint.iter_mut()
+    {
+        *hint_visibility = Visibility::Visible;
+        place_hint(&mut hint_transform, focused_transform, focused_selectable);
+    }
+}
+
+// 힌트를 선택 대상과 같은 위치, 같은 크기로 맞춤
+fn place_hint(hint_transform: &mut Transform, target_transform: &Transform, target_selectable: &MouseSelectable) {
+    // 위치를 선택 대상과 동일하게 업데이트
+    hint_transform
+        .translation
+        .x = target_transform
+        .translation
+        .x;
+    hint_transform
+        .translation
+        .y = target_transform
+        .translation
+        .y;
+
+    // 크기를 선택 대상과 동일하게 업데이트
+    hint_transform
+        .scale
+        .x = target_selectable.select_rect.width() * 0.1; // 화면 배율에 따라 조정해야겠지만 지금은 임시
+
+    hint_transform
+        .scale
+        .y = target_selectable.select_rect.height() * 0.1; // 화면 배율에 따라 조정해야겠지만 지금은 임시
+}
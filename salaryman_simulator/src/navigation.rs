@@ -0,0 +1,407 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use board_plugin::components::Coordinates;
+use board_plugin::resources::TileMap;
+
+use crate::components::{Desk, Person, DESK_SHAPE, PERSON_SHAPE};
+use crate::physics::{move_and_slide, Collider, ColliderShape, Obstacle, ObstacleQuery};
+use crate::player::Player;
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+/// 길을 막는 물체. 사람이 이 사각형과 부딪히는 칸은 지나갈 수 없습니다.
+#[derive(Reflect, Component, Debug, Clone)]
+#[reflect(Component)]
+pub struct NavBlocker {
+    pub half_size: Vec2,
+}
+
+/// 타일맵의 벽과 길을 막는 물체로 만든 이동 격자
+#[derive(Resource, Debug, Clone)]
+pub struct NavGrid {
+    width: u16,
+    height: u16,
+    tile_size: f32,
+    walkable: Vec<bool>,
+}
+
+impl NavGrid {
+    pub fn from_tile_map(tile_map: &TileMap) -> Self {
+        let mut walkable = Vec::with_capacity(tile_map.width() as usize * tile_map.height() as usize);
+        for y in 0..tile_map.height() {
+            for x in 0..tile_map.width() {
+                walkable.push(
+                    tile_map
+                        .get(Coordinates::new(x, y))
+                        .is_some_and(|tile| tile.is_walkable()),
+                );
+            }
+        }
+        NavGrid {
+            width: tile_map.width(),
+            height: tile_map.height(),
+            tile_size: tile_map.tile_size(),
+            walkable,
+        }
+    }
+
+    fn index(&self, cell: Coordinates) -> usize {
+        cell.y as usize * self.width as usize + cell.x as usize
+    }
+
+    pub fn is_walkable(&self, cell: Coordinates) -> bool {
+        cell.x < self.width
+            && cell.y < self.height
+            && self.walkable[self.index(cell)]
+    }
+
+    /// 칸 중심에 사람이 서면 `center` 주변 사각형과 부딪히는 칸을 막음
+    pub fn block(&mut self, center: Vec2, half_size: Vec2) {
+        let person = ColliderShape::from(PERSON_SHAPE);
+        let blocker = ColliderShape::Rect { half_size };
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell = Coordinates::new(x, y);
+                if person.overlaps(self.cell_to_world(cell), &blocker, center) {
+                    let index = self.index(cell);
+                    self.walkable[index] = false;
+                }
+            }
+        }
+    }
+
+    fn world_size(&self) -> Vec2 {
+        Vec2::new(self.width as f32, self.height as f32) * self.tile_size
+    }
+
+    /// 칸 중심의 월드 위치 (TileMap::tile_to_world와 같음)
+    pub fn cell_to_world(&self, cell: Coordinates) -> Vec2 {
+        (Vec2::new(cell.x as f32, cell.y as f32) + 0.5) * self.tile_size - self.world_size() / 2.0
+    }
+
+    /// 월드 위치가 속한 칸. 격자 밖이면 None
+    pub fn world_to_cell(&self, position: Vec2) -> Option<Coordinates> {
+        let local = (position + self.world_size() / 2.0) / self.tile_size;
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+        let (x, y) = (local.x as u16, local.y as u16);
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(Coordinates::new(x, y))
+    }
+
+    /// 8방향 이웃과 이동 비용. 벽 모서리를 가로지르는 대각선은 제외
+    fn neighbors(&self, cell: Coordinates) -> Vec<(Coordinates, u32)> {
+        let offset = |dx: i32, dy: i32| {
+            let x = cell.x as i32 + dx;
+            let y = cell.y as i32 + dy;
+            (x >= 0 && y >= 0).then(|| Coordinates::new(x as u16, y as u16))
+        };
+        let walkable = |dx: i32, dy: i32| offset(dx, dy).is_some_and(|next| self.is_walkable(next));
+
+        let mut neighbors = Vec::with_capacity(8);
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)] {
+            let diagonal = dx != 0 && dy != 0;
+            if !walkable(dx, dy) || (diagonal && !(walkable(dx, 0) && walkable(0, dy))) {
+                continue;
+            }
+            let cost = if diagonal { DIAGONAL_COST } else { STRAIGHT_COST };
+            if let Some(next) = offset(dx, dy) {
+                neighbors.push((next, cost));
+            }
+        }
+        neighbors
+    }
+
+    /// 막힌 칸이면 가장 가까운 지나갈 수 있는 칸
+    pub fn nearest_walkable(&self, cell: Coordinates) -> Option<Coordinates> {
+        if self.is_walkable(cell) {
+            return Some(cell);
+        }
+        (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| Coordinates::new(x, y)))
+            .filter(|candidate| self.is_walkable(*candidate))
+            .min_by_key(|candidate| {
+                let dx = candidate.x as i32 - cell.x as i32;
+                let dy = candidate.y as i32 - cell.y as i32;
+                dx * dx + dy * dy
+            })
+    }
+
+    /// A*로 찾은 칸 목록 (출발 칸 제외, 도착 칸 포함). 길이 없으면 None
+    pub fn find_path(&self, start: Coordinates, goal: Coordinates) -> Option<Vec<Coordinates>> {
+        let heuristic = |cell: Coordinates| {
+            let dx = (cell.x as i32 - goal.x as i32).unsigned_abs();
+            let dy = (cell.y as i32 - goal.y as i32).unsigned_abs();
+            STRAIGHT_COST * dx.max(dy) + (DIAGONAL_COST - STRAIGHT_COST) * dx.min(dy)
+        };
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<Coordinates, Coordinates> = HashMap::new();
+        let mut costs: HashMap<Coordinates, u32> = HashMap::new();
+        open.push(Reverse((heuristic(start), start)));
+        costs.insert(start, 0);
+
+        while let Some(Reverse((_, cell))) = open.pop() {
+            if cell == goal {
+                let mut path = vec![goal];
+                let mut current = goal;
+                while let Some(previous) = came_from.get(&current) {
+                    if *previous == start {
+                        break;
+                    }
+                    path.push(*previous);
+                    current = *previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let cost = costs[&cell];
+            for (next, step) in self.neighbors(cell) {
+                let next_cost = cost + step;
+                if costs
+                    .get(&next)
+                    .is_some_and(|known| *known <= next_cost)
+                {
+                    continue;
+                }
+                costs.insert(next, next_cost);
+                came_from.insert(next, cell);
+                open.push(Reverse((next_cost + heuristic(next), next)));
+            }
+        }
+        None
+    }
+}
+
+/// 경로를 따라 Person.speed로 걸어가는 사람
+#[derive(Component, Debug, Default)]
+pub struct PathFollower {
+    goal: Option<Vec2>,
+    waypoints: VecDeque<Vec2>,
+    needs_path: bool,
+}
+
+impl PathFollower {
+    /// 목적지가 바뀌면 다음 틱에 길을 다시 찾음
+    pub fn go_to(&mut self, goal: Vec2) {
+        if self.goal != Some(goal) {
+            self.goal = Some(goal);
+            self.needs_path = true;
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.goal = None;
+        self.waypoints
+            .clear();
+        self.needs_path = false;
+    }
+
+    pub fn goal(&self) -> Option<Vec2> {
+        self.goal
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.goal
+            .is_some()
+    }
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NavigationSet;
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (add_desk_blockers, build_nav_grid, find_paths, follow_paths)
+                .chain()
+                .in_set(NavigationSet),
+        )
+        .configure_sets(FixedUpdate, NavigationSet.in_set(crate::WorldUpdateSet));
+    }
+}
+
+// 책상은 길을 막음 (새로 생성되거나 예전 저장 파일에서 불러온 경우)
+fn add_desk_blockers(mut commands: Commands, q_desks: Query<(Entity, Has<NavBlocker>), With<Desk>>) {
+    for (entity, has_blocker) in q_desks.iter() {
+        if has_blocker {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(NavBlocker {
+                half_size: DESK_SHAPE.half_size,
+            });
+    }
+}
+
+// 타일맵이 바뀌거나 길을 막는 물체가 생기거나 사라지면 격자를 다시 만들고 모두 길을 다시 찾음
+fn build_nav_grid(
+    mut commands: Commands,
+    tile_map: Option<Res<TileMap>>,
+    q_blockers: Query<(&Transform, &NavBlocker)>,
+    q_changed: Query<(), Changed<NavBlocker>>,
+    mut removed: RemovedComponents<NavBlocker>,
+    mut q_followers: Query<&mut PathFollower>,
+) {
+    let Some(tile_map) = tile_map else {
+        return;
+    };
+    let blockers_changed = !q_changed.is_empty()
+        || removed
+            .read()
+            .count()
+            > 0;
+    if !tile_map.is_changed() && !blockers_changed {
+        return;
+    }
+
+    let mut nav_grid = NavGrid::from_tile_map(&tile_map);
+    for (transform, blocker) in q_blockers.iter() {
+        nav_grid.block(
+            transform
+                .translation
+                .truncate(),
+            blocker.half_size,
+        );
+    }
+    commands.insert_resource(nav_grid);
+    for mut follower in q_followers.iter_mut() {
+        if follower.is_moving() {
+            follower.needs_path = true;
+        }
+    }
+}
+
+// 목적지가 바뀐 사람의 경로를 찾음. 격자 밖이거나 길이 없으면 곧장 걸어감
+fn find_paths(nav_grid: Option<Res<NavGrid>>, mut q_followers: Query<(&Transform, &mut PathFollower)>) {
+    for (transform, mut follower) in q_followers.iter_mut() {
+        if !follower.needs_path {
+            continue;
+        }
+        follower.needs_path = false;
+        let Some(goal) = follower.goal else {
+            continue;
+        };
+        let position = transform
+            .translation
+            .truncate();
+
+        let path = nav_grid
+            .as_ref()
+            .and_then(|nav_grid| {
+                let start = nav_grid.world_to_cell(position)?;
+                let goal_cell = nav_grid.world_to_cell(goal)?;
+                let reachable_goal = nav_grid.nearest_walkable(goal_cell)?;
+                let cells = nav_grid.find_path(start, reachable_goal)?;
+                let mut waypoints: VecDeque<Vec2> = cells
+                    .iter()
+                    .map(|cell| nav_grid.cell_to_world(*cell))
+                    .collect();
+                // 도착 칸이 막혀 있지 않으면 칸 중심이 아닌 목적지까지 감
+                if reachable_goal == goal_cell {
+                    waypoints.pop_back();
+                    waypoints.push_back(goal);
+                }
+                Some(waypoints)
+            });
+        follower.waypoints = path.unwrap_or_else(|| VecDeque::from([goal]));
+    }
+}
+
+/// 경로를 따라 걷는 사람
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct Walker {
+    entity: Entity,
+    transform: &'static mut Transform,
+    person: &'static Person,
+    follower: &'static mut PathFollower,
+    collider: Option<&'static Collider>,
+    is_player: Has<Player>,
+}
+
+// 경유지를 차례로 따라 걸어감. 동료들은 책상과 벽에만, 플레이어는 다른 사람에게도 부딪힘
+fn follow_paths(time: Res<Time>, mut queries: ParamSet<(Query<ObstacleQuery>, Query<Walker>)>) {
+    let obstacles: Vec<Obstacle> = queries
+        .p0()
+        .iter()
+        .map(|obstacle| obstacle.obstacle())
+        .collect();
+    let static_obstacles: Vec<Obstacle> = obstacles
+        .iter()
+        .filter(|obstacle| obstacle.is_static)
+        .copied()
+        .collect();
+
+    for mut walker in queries
+        .p1()
+        .iter_mut()
+    {
+        if walker.person.is_dead || !walker.follower.is_moving() {
+            continue;
+        }
+        let obstacles = if walker.is_player { &obstacles } else { &static_obstacles };
+        let mut step = walker.person.speed * time.delta_seconds();
+        let start = walker
+            .transform
+            .translation
+            .truncate();
+        let mut position = start;
+        while let Some(waypoint) = walker
+            .follower
+            .waypoints
+            .front()
+            .copied()
+        {
+            let distance = position.distance(waypoint);
+            let target = if distance > step {
+                position + (waypoint - position) / distance * step
+            } else {
+                waypoint
+            };
+            let moved = match walker.collider {
+                Some(collider) => move_and_slide(walker.entity, position, target - position, &collider.shape, obstacles),
+                None => target,
+            };
+            if moved != target {
+                // 부딪히면 가던 데까지만 가고 다음 틱에 다시 시도
+                position = moved;
+                break;
+            }
+            position = target;
+            if distance > step {
+                break;
+            }
+            step -= distance;
+            walker
+                .follower
+                .waypoints
+                .pop_front();
+        }
+        walker.transform.translation = position.extend(walker.transform.translation.z);
+
+        // 마지막 경유지에 닿거나 완전히 막히면 멈춤. 목적지가 막혀 있으면 가장 가까운 칸에서 멈춤
+        let stuck = time.delta_seconds() > 0.0 && position == start;
+        if (walker
+            .follower
+            .waypoints
+            .is_empty()
+            || stuck)
+            && !walker.follower.needs_path
+        {
+            walker
+                .follower
+                .stop();
+        }
+    }
+}
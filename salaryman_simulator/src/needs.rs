@@ -0,0 +1,301 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{GameClock, HourStarted, WorkHoursEnded};
+use crate::components::{Person, BASE_SPEED};
+use crate::dialogue::DialogueEnded;
+use crate::player::Player;
+
+/// 욕구 수치의 최댓값
+pub const NEED_MAX: f32 = 100.0;
+
+/// 사람의 욕구 (0 ~ 100)
+///
+/// 배고픔, 피로, 스트레스는 낮을수록, 의욕과 사교는 높을수록 좋습니다.
+#[derive(Reflect, Component, Debug, Clone)]
+#[reflect(Component)]
+pub struct Needs {
+    pub hunger: f32,
+    pub fatigue: f32,
+    pub stress: f32,
+    pub motivation: f32,
+    pub social: f32,
+}
+
+impl Default for Needs {
+    fn default() -> Self {
+        Needs {
+            hunger: 20.0,
+            fatigue: 10.0,
+            stress: 10.0,
+            motivation: 80.0,
+            social: 70.0,
+        }
+    }
+}
+
+impl Needs {
+    /// 굶주림과 과로가 모두 한계에 닿으면 쓰러짐
+    pub fn is_collapsed(&self) -> bool {
+        self.hunger >= NEED_MAX && self.fatigue >= NEED_MAX
+    }
+
+    /// 스트레스는 한계인데 의욕이 바닥남
+    pub fn is_burned_out(&self) -> bool {
+        self.stress >= NEED_MAX && self.motivation <= 0.0
+    }
+
+    /// 이동 속도 배율. 피곤하고 배고플수록 느려집니다.
+    pub fn speed_factor(&self) -> f32 {
+        (1.0 - 0.5 * self.fatigue / NEED_MAX - 0.2 * self.hunger / NEED_MAX).max(0.3)
+    }
+
+    /// 업무 효율 (0 ~ 1). 의욕이 높고 피로와 스트레스가 낮을수록 높습니다.
+    pub fn work_efficiency(&self) -> f32 {
+        let motivation = 0.5 + 0.5 * self.motivation / NEED_MAX;
+        let fatigue = 1.0 - 0.5 * self.fatigue / NEED_MAX;
+        let stress = 1.0 - 0.5 * self.stress / NEED_MAX;
+        (motivation * fatigue * stress).clamp(0.0, 1.0)
+    }
+
+    fn clamp(&mut self) {
+        for need in [
+            &mut self.hunger,
+            &mut self.fatigue,
+            &mut self.stress,
+            &mut self.motivation,
+            &mut self.social,
+        ] {
+            *need = need.clamp(0.0, NEED_MAX);
+        }
+    }
+
+    pub fn restore(&mut self, source: NeedsRestore) {
+        match source {
+            NeedsRestore::Coffee => {
+                self.fatigue -= 20.0;
+                self.motivation += 5.0;
+            }
+            NeedsRestore::Lunch => {
+                self.hunger = 0.0;
+                self.social += 10.0;
+            }
+            NeedsRestore::Chat => {
+                self.social += 15.0;
+                self.stress -= 5.0;
+            }
+            NeedsRestore::GoHome => {
+                self.hunger -= 30.0;
+                self.fatigue -= 60.0;
+                self.stress -= 40.0;
+                self.motivation += 20.0;
+            }
+            NeedsRestore::Work => {
+                self.fatigue += 5.0;
+                self.stress += 5.0;
+            }
+        }
+        self.clamp();
+    }
+}
+
+impl fmt::Display for Needs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "배고픔 {:.0} / 피로 {:.0} / 스트레스 {:.0} / 의욕 {:.0} / 사교 {:.0}",
+            self.hunger, self.fatigue, self.stress, self.motivation, self.social
+        )
+    }
+}
+
+/// 번아웃 상태. 스트레스가 충분히 풀리면 사라집니다.
+#[derive(Reflect, Component, Default, Debug)]
+#[reflect(Component)]
+pub struct Burnout;
+
+/// 욕구를 채우거나 소모하는 행동
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NeedsRestore {
+    Coffee,
+    Lunch,
+    Chat,
+    GoHome,
+    /// 책상에서 일하면 피곤해지고 스트레스를 받음
+    Work,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RestoreNeeds {
+    pub entity: Entity,
+    pub source: NeedsRestore,
+}
+
+/// 게임 시간 1시간마다 변하는 욕구의 양
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct NeedsConfig {
+    pub hunger_per_hour: f32,
+    pub fatigue_per_work_hour: f32,
+    pub fatigue_per_rest_hour: f32,
+    pub stress_per_work_hour: f32,
+    pub stress_per_rest_hour: f32,
+    pub motivation_per_work_hour: f32,
+    pub motivation_per_rest_hour: f32,
+    pub social_per_hour: f32,
+    /// 매일 이 시각에 모두 점심을 먹음
+    pub lunch_hour: u32,
+    /// 번아웃에서 회복되는 스트레스
+    pub burnout_recovery_stress: f32,
+}
+
+impl Default for NeedsConfig {
+    fn default() -> Self {
+        NeedsConfig {
+            hunger_per_hour: 5.0,
+            fatigue_per_work_hour: 6.0,
+            fatigue_per_rest_hour: -3.0,
+            stress_per_work_hour: 4.0,
+            stress_per_rest_hour: -2.0,
+            motivation_per_work_hour: -2.0,
+            motivation_per_rest_hour: 1.0,
+            social_per_hour: -2.0,
+            lunch_hour: 12,
+            burnout_recovery_stress: 50.0,
+        }
+    }
+}
+
+pub struct NeedsPlugin;
+
+impl Plugin for NeedsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NeedsConfig>()
+            .add_event::<RestoreNeeds>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    add_needs,
+                    decay_needs,
+                    (lunch_break, go_home, chat_after_dialogue),
+                    restore_needs,
+                    apply_needs,
+                )
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 욕구가 없는 사람에게 기본 욕구를 줌 (새로 생성되거나 예전 저장 파일에서 불러온 경우)
+fn add_needs(mut commands: Commands, q_people: Query<Entity, (With<Person>, Without<Needs>)>) {
+    for entity in q_people.iter() {
+        commands
+            .entity(entity)
+            .insert(Needs::default());
+    }
+}
+
+// 게임 시간이 흐르는 만큼 욕구가 줄어듦. 근무 시간에는 더 빨리 지침
+fn decay_needs(
+    time: Res<Time>,
+    clock: Res<GameClock>,
+    config: Res<NeedsConfig>,
+    mut q_needs: Query<&mut Needs>,
+) {
+    let hours = clock.scaled_delta_hours(&time);
+    let (fatigue, stress, motivation) = if clock.is_work_hours() {
+        (
+            config.fatigue_per_work_hour,
+            config.stress_per_work_hour,
+            config.motivation_per_work_hour,
+        )
+    } else {
+        (
+            config.fatigue_per_rest_hour,
+            config.stress_per_rest_hour,
+            config.motivation_per_rest_hour,
+        )
+    };
+    for mut needs in q_needs.iter_mut() {
+        needs.hunger += config.hunger_per_hour * hours;
+        needs.fatigue += fatigue * hours;
+        needs.stress += stress * hours;
+        needs.motivation += motivation * hours;
+        needs.social += config.social_per_hour * hours;
+        needs.clamp();
+    }
+}
+
+// 점심시간이 되면 모두 점심을 먹음
+fn lunch_break(
+    mut hour_started: EventReader<HourStarted>,
+    config: Res<NeedsConfig>,
+    q_people: Query<Entity, With<Needs>>,
+    mut restore: EventWriter<RestoreNeeds>,
+) {
+    for event in hour_started.read() {
+        if event.hour != config.lunch_hour {
+            continue;
+        }
+        for entity in q_people.iter() {
+            restore.send(RestoreNeeds {
+                entity,
+                source: NeedsRestore::Lunch,
+            });
+        }
+    }
+}
+
+// 퇴근하면 모두 집에서 쉼
+fn go_home(
+    mut work_hours_ended: EventReader<WorkHoursEnded>,
+    q_people: Query<Entity, With<Needs>>,
+    mut restore: EventWriter<RestoreNeeds>,
+) {
+    for _ in work_hours_ended.read() {
+        for entity in q_people.iter() {
+            restore.send(RestoreNeeds {
+                entity,
+                source: NeedsRestore::GoHome,
+            });
+        }
+    }
+}
+
+// 대화를 마치면 플레이어와 상대 모두 사교 욕구가 채워짐
+fn chat_after_dialogue(
+    mut dialogue_ended: EventReader<DialogueEnded>,
+    q_player: Query<Entity, With<Player>>,
+    mut restore: EventWriter<RestoreNeeds>,
+) {
+    for event in dialogue_ended.read() {
+        for entity in q_player
+            .iter()
+            .chain(std::iter::once(event.partner))
+        {
+            restore.send(RestoreNeeds {
+                entity,
+                source: NeedsRestore::Chat,
+            });
+        }
+    }
+}
+
+fn restore_needs(mut events: EventReader<RestoreNeeds>, mut q_needs: Query<&mut Needs>) {
+    for event in events.read() {
+        if let Ok(mut needs) = q_needs.get_mut(event.entity) {
+            needs.restore(event.source);
+        }
+    }
+}
+
+// 욕구에 따라 이동 속도를 바꿈
+fn apply_needs(mut q_people: Query<(&mut Person, &Needs, Has<Burnout>)>) {
+    for (mut person, needs, burned_out) in q_people.iter_mut() {
+        let burnout_factor = if burned_out { 0.5 } else { 1.0 };
+        person.speed = BASE_SPEED * needs.speed_factor() * burnout_factor;
+    }
+}
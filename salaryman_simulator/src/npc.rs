@@ -0,0 +1,587 @@
+use std::fmt;
+
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use board_plugin::resources::{Tile, TileMap};
+
+use crate::career::Fired;
+use crate::clock::{GameClock, HourStarted, Weekday};
+use crate::components::{Desk, Person};
+use crate::navigation::{NavigationSet, PathFollower};
+use crate::needs::{Burnout, Needs, NeedsConfig, NeedsRestore, RestoreNeeds, NEED_MAX};
+use crate::player::Player;
+use crate::tasks::{TaskList, Working, WORK_DISTANCE};
+
+/// 목적지에 이만큼 가까워지면 도착
+const ARRIVE_DISTANCE: f32 = 4.0;
+/// 책상 왼쪽에 서는 위치 (책상과 부딪히지 않고 업무 거리 안)
+const DESK_OFFSET: Vec2 = Vec2::new(-50.0, 0.0);
+/// 퇴근 시간 뒤로 야근할 수 있는 시간
+const OVERTIME_HOURS: u32 = 3;
+/// 일정에 적힌 행동의 기본 점수
+const SCHEDULE_UTILITY: f32 = 0.5;
+/// 대화 상대에게 이만큼 가까워지면 대화
+const CHAT_DISTANCE: f32 = 50.0;
+
+/// 동료가 하는 행동
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Activity {
+    /// 회사 밖 (출근 전, 퇴근 후, 주말)
+    #[default]
+    Home,
+    /// 출근해서 책상으로 감
+    Commute,
+    DeskWork,
+    Lunch,
+    Meeting,
+    /// 퇴근 시간이 지나도 책상에서 일함
+    Overtime,
+    /// 밖에 나가서 커피를 사 옴
+    CoffeeBreak,
+    /// 가까운 동료에게 가서 잡담
+    Chat,
+}
+
+impl Activity {
+    pub fn is_work(&self) -> bool {
+        matches!(self, Activity::Commute | Activity::DeskWork | Activity::Overtime)
+    }
+}
+
+impl fmt::Display for Activity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Activity::Home => "퇴근",
+            Activity::Commute => "출근",
+            Activity::DeskWork => "업무",
+            Activity::Lunch => "점심",
+            Activity::Meeting => "회의",
+            Activity::Overtime => "야근",
+            Activity::CoffeeBreak => "커피",
+            Activity::Chat => "잡담",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 하루 일정의 한 구간 [start_hour, end_hour)
+#[derive(Reflect, Debug, Clone)]
+pub struct ScheduleBlock {
+    pub start_hour: u32,
+    pub end_hour: u32,
+    pub activity: Activity,
+    /// 정해진 요일에만 하는 일정 (주간 회의 등)
+    pub weekday: Option<Weekday>,
+}
+
+/// 평일 하루 일정. 앞에 있는 구간이 우선하고, 주말에는 쉽니다.
+#[derive(Reflect, Component, Debug, Clone)]
+#[reflect(Component)]
+pub struct Schedule {
+    pub blocks: Vec<ScheduleBlock>,
+}
+
+impl Schedule {
+    /// 출근 1시간 전부터 출근, 점심, 월요일 오후 주간 회의가 있는 일정
+    pub fn office(work_start_hour: u32, work_end_hour: u32, lunch_hour: u32) -> Self {
+        let block = |start_hour: u32, end_hour: u32, activity: Activity, weekday: Option<Weekday>| {
+            ScheduleBlock {
+                start_hour,
+                end_hour,
+                activity,
+                weekday,
+            }
+        };
+        Schedule {
+            blocks: vec![
+                block(lunch_hour, lunch_hour + 1, Activity::Lunch, None),
+                block(lunch_hour + 2, lunch_hour + 3, Activity::Meeting, Some(Weekday::Monday)),
+                block(work_start_hour.saturating_sub(1), work_start_hour, Activity::Commute, None),
+                block(work_start_hour, work_end_hour, Activity::DeskWork, None),
+            ],
+        }
+    }
+
+    pub fn activity_at(&self, hour: u32, weekday: Weekday) -> Activity {
+        if weekday.is_weekend() {
+            return Activity::Home;
+        }
+        self.blocks
+            .iter()
+            .filter(|block| block.weekday.is_none_or(|day| day == weekday))
+            .find(|block| block.start_hour <= hour && hour < block.end_hour)
+            .map_or(Activity::Home, |block| block.activity)
+    }
+}
+
+/// 동료의 현재 행동과 목적지
+#[derive(Component, Debug, Default)]
+pub struct NpcBrain {
+    pub activity: Activity,
+    /// 일정에 적힌 원래 행동
+    pub scheduled: Activity,
+    pub desk: Option<Entity>,
+    pub target: Option<Vec2>,
+    /// 잡담하러 가는 상대
+    pub chat_partner: Option<Entity>,
+    pub arrived: bool,
+    /// 다음 틱에 행동을 다시 정함
+    decided: bool,
+}
+
+/// 동료가 다른 사람에게 가서 잡담을 나눔
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Chatted {
+    pub speaker: Entity,
+    pub listener: Entity,
+}
+
+/// 업무 마감이 얼마나 급한지 (0 ~ 1)
+pub fn task_urgency(task_list: Option<&TaskList>, today: u32) -> f32 {
+    let Some(task_list) = task_list else {
+        return 0.0;
+    };
+    match task_list
+        .tasks
+        .iter()
+        .map(|task| task.deadline_day)
+        .min()
+    {
+        Some(deadline) if deadline <= today + 1 => 1.0,
+        Some(_) => 0.3,
+        None => 0.0,
+    }
+}
+
+/// 일정과 욕구로 계산한 행동의 점수
+pub fn utility(activity: Activity, scheduled: Activity, needs: &Needs, urgency: f32, burned_out: bool) -> f32 {
+    let fatigue = needs.fatigue / NEED_MAX;
+    let loneliness = 1.0 - needs.social / NEED_MAX;
+    match activity {
+        // 책상에서 일하는 중에만 잠깐 쉴 수 있음
+        Activity::CoffeeBreak if scheduled == Activity::DeskWork => 0.9 * fatigue,
+        Activity::Chat if scheduled == Activity::DeskWork => 0.8 * loneliness,
+        Activity::Overtime if scheduled == Activity::Home => urgency * (1.0 - fatigue),
+        Activity::DeskWork if activity == scheduled && burned_out => SCHEDULE_UTILITY * 0.5,
+        _ if activity == scheduled => SCHEDULE_UTILITY,
+        _ => 0.0,
+    }
+}
+
+/// 점수가 가장 높은 행동. 같으면 일정을 따름
+pub fn choose_activity(scheduled: Activity, needs: &Needs, urgency: f32, burned_out: bool) -> Activity {
+    [Activity::CoffeeBreak, Activity::Chat, Activity::Overtime]
+        .into_iter()
+        .map(|activity| (activity, utility(activity, scheduled, needs, urgency, burned_out)))
+        .fold(
+            (scheduled, utility(scheduled, scheduled, needs, urgency, burned_out)),
+            |best, candidate| if candidate.1 > best.1 { candidate } else { best },
+        )
+        .0
+}
+
+pub struct NpcPlugin;
+
+impl Plugin for NpcPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Chatted>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    add_npc_brains,
+                    assign_desks,
+                    decide_activities,
+                    update_targets,
+                    steer_npcs,
+                    arrive,
+                )
+                    .chain()
+                    .before(NavigationSet)
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 플레이어가 아닌 사람에게 일정과 행동을 줌
+fn add_npc_brains(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    config: Res<NeedsConfig>,
+    q_people: Query<(Entity, Has<Player>, Has<NpcBrain>), With<Person>>,
+) {
+    for (entity, is_player, has_brain) in q_people.iter() {
+        if is_player || has_brain {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert((
+                Schedule::office(clock.work_start_hour, clock.work_end_hour, config.lunch_hour),
+                NpcBrain::default(),
+                PathFollower::default(),
+            ));
+    }
+}
+
+// 책상이 없는 동료에게 가장 가까운 빈 책상을 줌. 배치도가 바뀌어 책상이 사라진 경우도 포함
+fn assign_desks(
+    mut q_brains: Query<(&mut NpcBrain, &Transform)>,
+    q_desks: Query<(Entity, &Transform), With<Desk>>,
+) {
+    let taken: Vec<Entity> = q_brains
+        .iter()
+        .filter_map(|(brain, _)| brain.desk)
+        .filter(|desk| q_desks.contains(*desk))
+        .collect();
+    let mut free: Vec<(Entity, Vec3)> = q_desks
+        .iter()
+        .filter(|(desk, _)| !taken.contains(desk))
+        .map(|(desk, transform)| (desk, transform.translation))
+        .collect();
+
+    for (mut brain, transform) in q_brains.iter_mut() {
+        if brain
+            .desk
+            .is_some_and(|desk| q_desks.contains(desk))
+        {
+            continue;
+        }
+        let nearest = free
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                a.1.distance_squared(transform.translation)
+                    .total_cmp(&b.1.distance_squared(transform.translation))
+            })
+            .map(|(index, _)| index);
+        brain.desk = nearest.map(|index| free.swap_remove(index).0);
+    }
+}
+
+/// 행동을 정하는 데 필요한 동료의 상태
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct NpcState {
+    entity: Entity,
+    brain: &'static mut NpcBrain,
+    schedule: &'static Schedule,
+    needs: Option<&'static Needs>,
+    task_list: Option<&'static TaskList>,
+    burned_out: Has<Burnout>,
+    working: Has<Working>,
+    fired: Has<Fired>,
+}
+
+// 매시 정각마다, 그리고 쉬는 시간이 끝나면 일정과 욕구로 다음 행동을 정함
+fn decide_activities(
+    mut commands: Commands,
+    mut hour_started: EventReader<HourStarted>,
+    clock: Res<GameClock>,
+    mut q_npcs: Query<NpcState>,
+) {
+    let new_hour = hour_started
+        .read()
+        .last()
+        .is_some();
+    let hour = clock.hour();
+    let weekday = clock.weekday();
+    let can_work_overtime = !weekday.is_weekend()
+        && hour >= clock.work_end_hour
+        && hour < clock.work_end_hour + OVERTIME_HOURS;
+
+    for mut npc in q_npcs.iter_mut() {
+        if !new_hour && npc.brain.decided {
+            continue;
+        }
+        // 해고되면 더 이상 출근하지 않음
+        let scheduled = if npc.fired {
+            Activity::Home
+        } else {
+            npc.schedule
+                .activity_at(hour, weekday)
+        };
+        let urgency = if can_work_overtime || scheduled != Activity::Home {
+            task_urgency(npc.task_list, clock.day())
+        } else {
+            0.0
+        };
+        let activity = match npc.needs {
+            Some(needs) => choose_activity(scheduled, needs, urgency, npc.burned_out),
+            None => scheduled,
+        };
+
+        if activity != npc.brain.activity {
+            npc.brain.arrived = false;
+            if npc.working && !activity.is_work() {
+                commands
+                    .entity(npc.entity)
+                    .remove::<Working>();
+            }
+        }
+        npc.brain.scheduled = scheduled;
+        npc.brain.activity = activity;
+        npc.brain.decided = true;
+    }
+}
+
+/// 행동에 따라 가는 곳 (책상, 출입문, 회의실, 가까운 동료)
+fn update_targets(
+    tile_map: Option<Res<TileMap>>,
+    mut q_brains: Query<(Entity, &mut NpcBrain, &Transform)>,
+    q_desks: Query<&Transform, With<Desk>>,
+    q_people: Query<(Entity, &Transform), With<Person>>,
+) {
+    let door = tile_map
+        .as_ref()
+        .and_then(|tile_map| {
+            tile_map
+                .tiles_of(Tile::Door)
+                .next()
+                .map(|door| tile_map.tile_to_world(door))
+        });
+    let meeting_seats: Vec<Vec2> = tile_map
+        .as_ref()
+        .map(|tile_map| {
+            tile_map
+                .tiles_of(Tile::MeetingRoom)
+                .map(|seat| tile_map.tile_to_world(seat))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for (entity, mut brain, transform) in q_brains.iter_mut() {
+        let position = transform
+            .translation
+            .truncate();
+        // 잡담하러 갈 때는 가장 가까운 사람에게 감
+        let chat_partner = if brain.activity == Activity::Chat {
+            q_people
+                .iter()
+                .filter(|(other, _)| *other != entity)
+                .min_by(|(_, a), (_, b)| {
+                    a.translation
+                        .truncate()
+                        .distance_squared(position)
+                        .total_cmp(
+                            &b.translation
+                                .truncate()
+                                .distance_squared(position),
+                        )
+                })
+        } else {
+            None
+        };
+        let target = match brain.activity {
+            Activity::Commute | Activity::DeskWork | Activity::Overtime => brain
+                .desk
+                .and_then(|desk| q_desks.get(desk).ok())
+                .map(|desk| {
+                    desk.translation
+                        .truncate()
+                        + DESK_OFFSET
+                }),
+            Activity::Home | Activity::Lunch | Activity::CoffeeBreak => door,
+            // 사람마다 다른 자리에 앉음
+            Activity::Meeting => (!meeting_seats.is_empty())
+                .then(|| meeting_seats[entity.index() as usize % meeting_seats.len()]),
+            Activity::Chat => chat_partner.map(|(_, partner)| {
+                partner
+                    .translation
+                    .truncate()
+            }),
+        };
+        if brain.target != target {
+            brain.target = target;
+        }
+        let chat_partner = chat_partner.map(|(partner, _)| partner);
+        if brain.chat_partner != chat_partner {
+            brain.chat_partner = chat_partner;
+        }
+    }
+}
+
+// 목적지까지 길을 찾아 걸어가게 하고, 충분히 가까워지면 도착
+fn steer_npcs(mut q_npcs: Query<(&Transform, &mut NpcBrain, &mut PathFollower)>) {
+    for (transform, mut brain, mut follower) in q_npcs.iter_mut() {
+        let Some(target) = brain.target else {
+            follower.stop();
+            continue;
+        };
+        let stop_distance = if brain.activity == Activity::Chat { CHAT_DISTANCE } else { ARRIVE_DISTANCE };
+        let arrived = transform
+            .translation
+            .truncate()
+            .distance(target)
+            <= stop_distance;
+        if arrived {
+            follower.stop();
+        } else {
+            follower.go_to(target);
+        }
+        if brain.arrived != arrived {
+            brain.arrived = arrived;
+        }
+    }
+}
+
+// 도착하면 책상에서 일을 시작하거나 커피, 잡담으로 욕구를 채우고 일정으로 돌아감
+fn arrive(
+    mut commands: Commands,
+    mut q_npcs: Query<(Entity, &mut NpcBrain, &Transform, Has<Working>)>,
+    q_desks: Query<&Transform, With<Desk>>,
+    mut restore: EventWriter<RestoreNeeds>,
+    mut chatted: EventWriter<Chatted>,
+) {
+    for (entity, mut brain, transform, working) in q_npcs.iter_mut() {
+        if !brain.arrived {
+            continue;
+        }
+        match brain.activity {
+            Activity::DeskWork | Activity::Overtime if !working => {
+                let Some(desk) = brain.desk else {
+                    continue;
+                };
+                let near_desk = q_desks
+                    .get(desk)
+                    .is_ok_and(|desk| {
+                        desk.translation
+                            .distance(transform.translation)
+                            < WORK_DISTANCE
+                    });
+                if near_desk {
+                    commands
+                        .entity(entity)
+                        .insert(Working { desk });
+                }
+            }
+            Activity::CoffeeBreak | Activity::Chat => {
+                let source = if brain.activity == Activity::Chat {
+                    NeedsRestore::Chat
+                } else {
+                    NeedsRestore::Coffee
+                };
+                restore.send(RestoreNeeds { entity, source });
+                if let (Activity::Chat, Some(listener)) = (brain.activity, brain.chat_partner) {
+                    chatted.send(Chatted {
+                        speaker: entity,
+                        listener,
+                    });
+                }
+                // 쉬고 나면 다음 정각까지 일정대로
+                brain.activity = brain.scheduled;
+                brain.arrived = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::InputPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::actions::{ActionMap, ActionsPlugin};
+    use crate::clock::ClockPlugin;
+    use crate::navigation::NavigationPlugin;
+    use crate::tick::{TickPlugin, TickRate};
+
+    /// 월요일 오전 8시 10초 전
+    const BEFORE_COMMUTE_SECONDS: f64 = 8.0 * 3600.0 - 10.0;
+
+    // 창 없이 시계, 길 찾기, 동료만 돌리는 앱. 업데이트마다 정확히 한 틱이 지남
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.insert_resource(ActionMap::default())
+            .add_plugins((
+                MinimalPlugins,
+                InputPlugin,
+                ActionsPlugin,
+                TickPlugin,
+                ClockPlugin,
+                NavigationPlugin,
+                NpcPlugin,
+            ))
+            .init_resource::<NeedsConfig>()
+            .add_event::<RestoreNeeds>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(TickRate::default().timestep()))
+            .insert_resource(TileMap::office(20, 15, 32.0))
+            .insert_resource(GameClock {
+                elapsed_seconds: BEFORE_COMMUTE_SECONDS,
+                ..default()
+            });
+        app
+    }
+
+    fn run(app: &mut App, updates: u32) {
+        for _ in 0..updates {
+            app.update();
+        }
+    }
+
+    fn npc_state(app: &mut App, npc: Entity) -> (Activity, Option<Vec2>, bool, Vec2) {
+        let brain = app
+            .world
+            .get::<NpcBrain>(npc)
+            .unwrap();
+        let state = (brain.activity, brain.target, brain.arrived);
+        let position = app
+            .world
+            .get::<Transform>(npc)
+            .unwrap()
+            .translation
+            .truncate();
+        (state.0, state.1, state.2, position)
+    }
+
+    #[test]
+    fn npc_commutes_to_desk_when_schedule_changes() {
+        let mut app = test_app();
+        let desk = app
+            .world
+            .spawn((Desk, Transform::from_xyz(200.0, 0.0, 0.0)))
+            .id();
+        let npc = app
+            .world
+            .spawn((Person::default(), Transform::default()))
+            .id();
+
+        // 출근 전에는 집에 가려고 출입문 쪽으로 걸어감
+        run(&mut app, 5);
+        assert_eq!(
+            app.world
+                .get::<NpcBrain>(npc)
+                .unwrap()
+                .desk,
+            Some(desk)
+        );
+        let (activity, target, _, position) = npc_state(&mut app, npc);
+        let door = app
+            .world
+            .resource::<TileMap>()
+            .tile_to_world(board_plugin::components::Coordinates::new(10, 0));
+        assert_eq!(activity, Activity::Home);
+        assert_eq!(target, Some(door));
+        assert!(position.distance(door) < Vec2::ZERO.distance(door), "{:?}", position);
+
+        // 8시가 되면 출근해서 책상 왼쪽으로 감
+        run(&mut app, 10);
+        let (activity, target, _, _) = npc_state(&mut app, npc);
+        let desk_spot = Vec2::new(200.0, 0.0) + DESK_OFFSET;
+        assert_eq!(activity, Activity::Commute);
+        assert_eq!(target, Some(desk_spot));
+
+        run(&mut app, 300);
+        let (activity, _, arrived, position) = npc_state(&mut app, npc);
+        assert_eq!(activity, Activity::Commute);
+        assert!(arrived);
+        assert!(position.distance(desk_spot) <= ARRIVE_DISTANCE, "{:?}", position);
+        assert_eq!(
+            app.world
+                .resource::<GameClock>()
+                .hour(),
+            8
+        );
+    }
+}
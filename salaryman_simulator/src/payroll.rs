@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+
+use crate::clock::{GameDate, Payday};
+use crate::components::Worker;
+use crate::money::{ExchangeRates, Money, MoneyError};
+
+/// 가지고 있는 돈
+#[derive(Reflect, Component, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct Wallet {
+    pub balance: Money,
+}
+
+/// 한 달치 급여 명세서 (원화)
+#[derive(Reflect, Debug, Clone)]
+pub struct Payslip {
+    pub date: GameDate,
+    pub gross: Money,
+    pub national_pension: Money,
+    pub health_insurance: Money,
+    pub long_term_care: Money,
+    pub employment_insurance: Money,
+    pub income_tax: Money,
+    pub local_income_tax: Money,
+}
+
+impl Payslip {
+    /// 4대보험 합계
+    pub fn insurance(&self) -> Result<Money, MoneyError> {
+        self.national_pension
+            .checked_add(self.health_insurance)?
+            .checked_add(self.long_term_care)?
+            .checked_add(self.employment_insurance)
+    }
+
+    /// 소득세와 지방소득세 합계
+    pub fn taxes(&self) -> Result<Money, MoneyError> {
+        self.income_tax
+            .checked_add(self.local_income_tax)
+    }
+
+    pub fn deductions(&self) -> Result<Money, MoneyError> {
+        self.insurance()?
+            .checked_add(self.taxes()?)
+    }
+
+    pub fn net(&self) -> Result<Money, MoneyError> {
+        self.gross
+            .checked_sub(self.deductions()?)
+    }
+}
+
+/// 지금까지 받은 급여 명세서
+#[derive(Reflect, Component, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct PayslipHistory {
+    pub payslips: Vec<Payslip>,
+}
+
+/// 과세표준 구간. `upper_bound`까지의 연 소득에 `rate`를 적용합니다. 상한이 없으면 None.
+#[derive(Reflect, Debug, Clone)]
+pub struct TaxBracket {
+    pub upper_bound: Option<Money>,
+    pub rate: f32,
+}
+
+/// 4대보험 요율과 소득세 구간
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct PayrollConfig {
+    pub national_pension_rate: f32,
+    pub health_insurance_rate: f32,
+    /// 건강보험료에 대한 비율
+    pub long_term_care_rate: f32,
+    pub employment_insurance_rate: f32,
+    /// 낮은 구간부터 순서대로. 마지막 구간을 넘는 소득에는 마지막 세율을 적용합니다.
+    pub income_tax_brackets: Vec<TaxBracket>,
+    /// 소득세에 대한 비율
+    pub local_income_tax_rate: f32,
+}
+
+impl Default for PayrollConfig {
+    fn default() -> Self {
+        let bracket = |upper_man_won: i64, rate: f32| TaxBracket {
+            upper_bound: Some(Money::man_won(upper_man_won)),
+            rate,
+        };
+        PayrollConfig {
+            national_pension_rate: 0.045,
+            health_insurance_rate: 0.03545,
+            long_term_care_rate: 0.1295,
+            employment_insurance_rate: 0.009,
+            income_tax_brackets: vec![
+                bracket(1_400, 0.06),
+                bracket(5_000, 0.15),
+                bracket(8_800, 0.24),
+                bracket(15_000, 0.35),
+                bracket(30_000, 0.38),
+                bracket(50_000, 0.40),
+                bracket(100_000, 0.42),
+                TaxBracket {
+                    upper_bound: None,
+                    rate: 0.45,
+                },
+            ],
+            local_income_tax_rate: 0.1,
+        }
+    }
+}
+
+impl PayrollConfig {
+    /// 연 소득에 대한 누진 소득세
+    pub fn annual_income_tax(&self, annual_income: Money) -> Result<Money, MoneyError> {
+        let mut tax = Money::zero(annual_income.currency);
+        let mut lower_bound = Money::zero(annual_income.currency);
+        for bracket in self
+            .income_tax_brackets
+            .iter()
+        {
+            if annual_income <= lower_bound {
+                break;
+            }
+            let upper_bound = match bracket.upper_bound {
+                Some(upper_bound) => annual_income.min(upper_bound),
+                None => annual_income,
+            };
+            let taxable = upper_bound.checked_sub(lower_bound)?;
+            tax = tax.checked_add(taxable.mul_rate(bracket.rate as f64)?)?;
+            lower_bound = upper_bound;
+        }
+        Ok(tax)
+    }
+
+    /// 연봉으로 한 달치 급여 명세서를 계산
+    pub fn payslip(&self, annual_salary: Money, date: GameDate) -> Result<Payslip, MoneyError> {
+        let gross = annual_salary.checked_div(12)?;
+        let health_insurance = gross.mul_rate(self.health_insurance_rate as f64)?;
+        let income_tax = self
+            .annual_income_tax(annual_salary)?
+            .checked_div(12)?;
+        Ok(Payslip {
+            date,
+            gross,
+            national_pension: gross.mul_rate(self.national_pension_rate as f64)?,
+            health_insurance,
+            long_term_care: health_insurance.mul_rate(self.long_term_care_rate as f64)?,
+            employment_insurance: gross.mul_rate(self.employment_insurance_rate as f64)?,
+            income_tax,
+            local_income_tax: income_tax.mul_rate(self.local_income_tax_rate as f64)?,
+        })
+    }
+}
+
+pub struct PayrollPlugin;
+
+impl Plugin for PayrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PayrollConfig>()
+            .add_systems(
+                FixedUpdate,
+                (add_wallets, pay_salaries)
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 지갑이 없는 직장인에게 빈 지갑과 명세서 목록을 줌 (새로 생성되거나 예전 저장 파일에서 불러온 경우)
+fn add_wallets(mut commands: Commands, q_workers: Query<Entity, (With<Worker>, Without<Wallet>)>) {
+    for entity in q_workers.iter() {
+        commands
+            .entity(entity)
+            .insert((Wallet::default(), PayslipHistory::default()));
+    }
+}
+
+// 월급날마다 연봉의 1/12에서 4대보험과 세금을 떼고 지갑에 넣음. 외화 연봉은 지갑 통화로 환전
+fn pay_salaries(
+    mut paydays: EventReader<Payday>,
+    config: Res<PayrollConfig>,
+    exchange_rates: Res<ExchangeRates>,
+    mut q_workers: Query<(&Name, &Worker, &mut Wallet, &mut PayslipHistory)>,
+) {
+    for payday in paydays.read() {
+        for (name, worker, mut wallet, mut history) in q_workers.iter_mut() {
+            let paid = worker
+                .salary
+                .amount
+                .convert(wallet.balance.currency, &exchange_rates)
+                .and_then(|annual_salary| config.payslip(annual_salary, payday.date))
+                .and_then(|payslip| {
+                    let net = payslip.net()?;
+                    let balance = wallet
+                        .balance
+                        .checked_add(net)?;
+                    Ok((payslip, net, balance))
+                });
+            let (payslip, net, balance) = match paid {
+                Ok(paid) => paid,
+                Err(error) => {
+                    error!("{} 급여를 계산할 수 없습니다: {}", name.as_str(), error);
+                    continue;
+                }
+            };
+            info!("{} 급여 지급 ({}): 실수령 {}", name.as_str(), payday.date, net);
+            wallet.balance = balance;
+            history
+                .payslips
+                .push(payslip);
+        }
+    }
+}
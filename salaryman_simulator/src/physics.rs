@@ -0,0 +1,171 @@
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+
+use crate::components::{Desk, Person, DESK_SHAPE, PERSON_SHAPE};
+
+/// 충돌 판정에 쓰는 모양. 중심은 엔티티의 Transform입니다.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShape {
+    Circle { radius: f32 },
+    Rect { half_size: Vec2 },
+}
+
+impl From<Circle> for ColliderShape {
+    fn from(circle: Circle) -> Self {
+        ColliderShape::Circle {
+            radius: circle.radius,
+        }
+    }
+}
+
+impl From<Rectangle> for ColliderShape {
+    fn from(rectangle: Rectangle) -> Self {
+        ColliderShape::Rect {
+            half_size: rectangle.half_size,
+        }
+    }
+}
+
+impl ColliderShape {
+    /// 두 모양이 겹치는지. 맞닿기만 한 것은 겹치지 않은 것으로 봅니다.
+    pub fn overlaps(&self, position: Vec2, other: &ColliderShape, other_position: Vec2) -> bool {
+        let offset = other_position - position;
+        match (*self, *other) {
+            (ColliderShape::Circle { radius }, ColliderShape::Circle { radius: other_radius }) => {
+                offset.length() < radius + other_radius
+            }
+            (ColliderShape::Circle { radius }, ColliderShape::Rect { half_size }) => {
+                circle_overlaps_rect(radius, -offset, half_size)
+            }
+            (ColliderShape::Rect { half_size }, ColliderShape::Circle { radius }) => {
+                circle_overlaps_rect(radius, offset, half_size)
+            }
+            (ColliderShape::Rect { half_size }, ColliderShape::Rect { half_size: other_half_size }) => {
+                let limit = half_size + other_half_size;
+                offset.x.abs() < limit.x && offset.y.abs() < limit.y
+            }
+        }
+    }
+}
+
+/// `offset`: 사각형 중심에서 원 중심까지
+fn circle_overlaps_rect(radius: f32, offset: Vec2, half_size: Vec2) -> bool {
+    let closest = offset.clamp(-half_size, half_size);
+    offset.distance_squared(closest) < radius * radius
+}
+
+#[derive(Reflect, Component, Debug, Clone)]
+#[reflect(Component)]
+pub struct Collider {
+    pub shape: ColliderShape,
+}
+
+impl Collider {
+    pub fn new(shape: impl Into<ColliderShape>) -> Self {
+        Collider {
+            shape: shape.into(),
+        }
+    }
+}
+
+/// 움직이지 않는 충돌체 (책상, 벽)
+#[derive(Reflect, Component, Default, Debug)]
+#[reflect(Component)]
+pub struct StaticCollider;
+
+/// 이번 틱에 부딪힐 수 있는 충돌체
+#[derive(Debug, Clone, Copy)]
+pub struct Obstacle {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub shape: ColliderShape,
+    pub is_static: bool,
+}
+
+/// 다른 사람의 위치를 바꾸기 전에 충돌체를 모아 두기 위한 쿼리
+#[derive(QueryData)]
+pub struct ObstacleQuery {
+    entity: Entity,
+    transform: &'static Transform,
+    collider: &'static Collider,
+    is_static: Has<StaticCollider>,
+}
+
+impl ObstacleQueryItem<'_> {
+    pub fn obstacle(&self) -> Obstacle {
+        Obstacle {
+            entity: self.entity,
+            position: self
+                .transform
+                .translation
+                .truncate(),
+            shape: self.collider.shape,
+            is_static: self.is_static,
+        }
+    }
+}
+
+/// x축, y축을 따로 움직여서 부딪힌 축만 막고 나머지 축으로는 미끄러짐
+///
+/// 이미 겹쳐 있는 충돌체는 빠져나갈 수 있도록 무시합니다.
+pub fn move_and_slide(
+    entity: Entity,
+    position: Vec2,
+    delta: Vec2,
+    shape: &ColliderShape,
+    obstacles: &[Obstacle],
+) -> Vec2 {
+    let mut position = position;
+    for step in [Vec2::new(delta.x, 0.0), Vec2::new(0.0, delta.y)] {
+        if step == Vec2::ZERO {
+            continue;
+        }
+        let next = position + step;
+        let blocked = obstacles
+            .iter()
+            .filter(|obstacle| obstacle.entity != entity)
+            .any(|obstacle| {
+                shape.overlaps(next, &obstacle.shape, obstacle.position)
+                    && !shape.overlaps(position, &obstacle.shape, obstacle.position)
+            });
+        if !blocked {
+            position = next;
+        }
+    }
+    position
+}
+
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            add_colliders.in_set(crate::WorldUpdateSet),
+        );
+    }
+}
+
+// 사람과 책상에 메시와 같은 모양의 충돌체를 붙임 (새로 생성되거나 예전 저장 파일에서 불러온 경우)
+fn add_colliders(
+    mut commands: Commands,
+    q_people: Query<(Entity, Has<Collider>), With<Person>>,
+    q_desks: Query<(Entity, Has<Collider>), With<Desk>>,
+) {
+    for (entity, _) in q_people
+        .iter()
+        .filter(|(_, has_collider)| !has_collider)
+    {
+        commands
+            .entity(entity)
+            .insert(Collider::new(PERSON_SHAPE));
+    }
+    for (entity, _) in q_desks
+        .iter()
+        .filter(|(_, has_collider)| !has_collider)
+    {
+        commands
+            .entity(entity)
+            .insert((Collider::new(DESK_SHAPE), StaticCollider));
+    }
+}
@@ -1,142 +1,229 @@
-use bevy::{input::*, prelude::*};
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
 
-use crate::components::{Interactable, InteractionTarget, InteractionType, Person};
-use crate::gui::components::{ChoiceUI, InteractionHintUI, PopUpUI};
+use crate::actions::{Action, ActionInput};
+use crate::clock::GameClock;
+use crate::components::{Interactable, MouseInput, Person};
+use crate::gui::components::{ChoiceUI, IncidentPopUp, PopUpUI};
+use crate::interaction::{
+    target_score, InteractionActorQuery, InteractionCandidate, InteractionStarted, InteractionTargets, Interacting,
+    INTERACT_DISTANCE,
+};
+use crate::navigation::PathFollower;
+use crate::physics::{move_and_slide, Collider, Obstacle, ObstacleQuery};
+use crate::tick::TickInput;
 
-#[derive(Component)]
+#[derive(Reflect, Component, Default)]
+#[reflect(Component)]
 pub struct Player;
 
+/// 마지막으로 움직인 방향. 상호작용 대상을 고를 때 바라보는 쪽을 앞에 둡니다.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Facing {
+    pub direction: Vec2,
+    last_position: Vec2,
+}
+
+// 상호작용 키를 누르면 대상의 행동을 시작하고, 시간이 걸리는 행동을 하는 중이면 취소
 pub fn interact(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    interactable_target_query: Query<(Entity, &InteractionTarget)>,
-    mut query: Query<(&mut Person, &Player)>,
-    mut choice_ui: Query<&mut ChoiceUI>,
+    mut commands: Commands,
+    actions: ActionInput,
+    targets: Res<InteractionTargets>,
+    q_choices: Query<&ChoiceUI>,
+    query: Query<(Entity, Has<Interacting>), With<Player>>,
+    mut started: EventWriter<InteractionStarted>,
 ) {
-    for (mut person, _) in query.iter_mut() {
-        if keyboard_input.just_pressed(KeyCode::KeyE) {
-            for (_, interact_target) in interactable_target_query.iter() {
-                if interact_target.is_interactable {
-                    match interact_target.interaction_type {
-                        InteractionType::Invalid => {}
-                        InteractionType::Work => {
-                            person.san -= 10;
-                        }
-                        InteractionType::Damage => {
-                            person.hp -= 10;
-                        }
-                        InteractionType::SalaryMan => {
-                            for mut _choice in choice_ui.iter_mut() {
-                                _choice.is_visible = true;
-                                _choice.tranform = interact_target
-                                    .target_transform
-                                    .clone();
-                                _choice.choices = vec![
-                                    "사원증 보기".to_string(),
-                                    "연봉 물어보기".to_string(),
-                                    "직군 물어보기".to_string(),
-                                    "직급 물어보기".to_string(),
-                                ];
-                            }
-                        }
-                    }
-                }
-            }
+    // 선택지를 고르는 중에는 같은 버튼을 선택에 씀
+    if q_choices
+        .iter()
+        .any(|choice| choice.is_visible)
+    {
+        return;
+    }
+    if !actions.just_pressed(Action::Interact) {
+        return;
+    }
+    for (player, interacting) in query.iter() {
+        if interacting {
+            commands
+                .entity(player)
+                .remove::<Interacting>();
+            continue;
+        }
+        if let Some(candidate) = targets.current() {
+            started.send(InteractionStarted {
+                actor: player,
+                target: candidate.target,
+                action: candidate.action,
+            });
         }
     }
 }
 
+/// 이동 키나 게임패드 스틱으로 움직이는 플레이어
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct PlayerMover {
+    entity: Entity,
+    transform: &'static mut Transform,
+    person: &'static Person,
+    collider: Option<&'static Collider>,
+    follower: Option<&'static mut PathFollower>,
+}
+
 pub fn player_movement(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut query: Query<(&mut Transform, &mut Person, &Player), With<Player>>,
+    time: Res<Time>,
+    actions: ActionInput,
+    mut query: Query<PlayerMover, With<Player>>,
+    q_obstacles: Query<ObstacleQuery, Without<Player>>,
 ) {
-    for (mut transform, person, _) in query.iter_mut() {
-        let mut direction = Vec3::ZERO;
-
-        if keyboard_input.pressed(KeyCode::KeyW) {
-            direction.y += 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyA) {
-            direction.x -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyS) {
-            direction.y -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyD) {
-            direction.x += 1.0;
-        }
+    let obstacles: Vec<Obstacle> = q_obstacles
+        .iter()
+        .map(|obstacle| obstacle.obstacle())
+        .collect();
 
+    for mut player in query.iter_mut() {
+        let direction = actions.movement();
         if direction.length_squared() > 0.0 {
-            transform.translation += direction.normalize() * person.speed * 0.02;
+            // 스틱을 덜 기울이면 천천히 걸음
+            let delta = direction * player.person.speed * time.delta_seconds();
+            let position = player
+                .transform
+                .translation
+                .truncate();
+            let moved = match player.collider {
+                Some(collider) => move_and_slide(player.entity, position, delta, &collider.shape, &obstacles),
+                None => position + delta,
+            };
+            player.transform.translation = moved.extend(player.transform.translation.z);
+            // 직접 움직이면 클릭으로 정한 목적지는 취소
+            if let Some(follower) = player.follower.as_mut() {
+                follower.stop();
+            }
         }
     }
 }
 
-pub fn player_check_collision(
-    query: Query<(&Transform, &Player)>,
-    interactable_query: Query<(Entity, &Interactable, &Transform, &Name), With<Interactable>>,
-    mut interaction_target_query: Query<(Entity, &mut InteractionTarget)>,
-    mut interaction_hint: Query<(&mut Visibility, &mut Text, &InteractionHintUI)>,
+// 길찾기가 없는 플레이어에게 줌 (새로 생성되거나 저장 파일에서 불러온 경우)
+pub fn add_path_follower(mut commands: Commands, query: Query<Entity, (With<Player>, Without<PathFollower>)>) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(PathFollower::default());
+    }
+}
+
+// 오른쪽 클릭한 곳으로 길을 찾아 걸어감
+pub fn click_to_move(
+    mouse_buttons: Res<TickInput<MouseButton>>,
+    q_mouse_inputs: Query<&MouseInput>,
+    mut query: Query<&mut PathFollower, With<Player>>,
 ) {
-    for (player_transform, _) in query.iter() {
-        let max_distance = 60.0;
-        let mut closest_distance = max_distance;
-        let mut closest: (Entity, &Interactable, &Transform, &Name) = (
-            Entity::PLACEHOLDER,
-            &Interactable::default(),
-            &Transform::default(),
-            &Name::default(),
-        );
+    if !mouse_buttons.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let Ok(mouse_input) = q_mouse_inputs.get_single() else {
+        return;
+    };
+    for mut follower in query.iter_mut() {
+        follower.go_to(mouse_input.world_position);
+    }
+}
 
-        for query in interactable_query.iter() {
-            let (_, _, interactable_transform, _) = query;
-            let distance = player_transform
-                .translation
-                .distance(interactable_transform.translation);
-            if distance < closest_distance {
-                closest_distance = distance;
-                closest = query;
-            }
+// 클릭으로 걸어갈 때도 방향이 바뀌도록 위치 변화로 바라보는 방향을 정함
+pub fn update_facing(mut commands: Commands, mut query: Query<(Entity, &Transform, Option<&mut Facing>), With<Player>>) {
+    for (entity, transform, facing) in query.iter_mut() {
+        let position = transform
+            .translation
+            .truncate();
+        let Some(mut facing) = facing else {
+            commands
+                .entity(entity)
+                .insert(Facing {
+                    direction: Vec2::NEG_Y,
+                    last_position: position,
+                });
+            continue;
+        };
+        let moved = position - facing.last_position;
+        if moved.length_squared() > f32::EPSILON {
+            facing.direction = moved.normalize();
+            facing.last_position = position;
         }
+    }
+}
 
-        // 인터랙션 가능할 만큼 충분히 가까운 대상이 존재함
-        if closest_distance < max_distance {
-            let (closest_entity, closest_interactable, transform, closest_name) = closest;
-            for (_, mut interact_target) in interaction_target_query.iter_mut() {
-                interact_target.is_interactable = true;
+/// 주변의 상호작용 대상을 찾는 플레이어
+#[derive(QueryData)]
+pub struct PlayerInteractor {
+    transform: &'static Transform,
+    actor: InteractionActorQuery,
+    facing: Option<&'static Facing>,
+}
 
-                if interact_target.target != closest_entity {
-                    interact_target.target = closest_entity;
-                    interact_target.target_transform = transform.clone();
-                    interact_target.interaction_type = closest_interactable
-                        .interaction_type
-                        .clone();
-                    for (mut visibility, mut _text, _hint) in interaction_hint.iter_mut() {
-                        *visibility = Visibility::Visible;
-                        _text.sections[0].value =
-                            format!("{} ({})", _hint.text, closest_name).to_string();
-                    }
-                }
+// 상호작용 거리 안에서 지금 할 수 있는 행동이 있는 대상을 모두 모아 가깝고 바라보는 쪽 순서로 정렬
+pub fn player_check_collision(
+    clock: Res<GameClock>,
+    mut targets: ResMut<InteractionTargets>,
+    query: Query<PlayerInteractor, With<Player>>,
+    interactable_query: Query<(Entity, &Interactable, &Transform, &Name)>,
+) {
+    let mut scored: Vec<(f32, InteractionCandidate)> = Vec::new();
+    for player in query.iter() {
+        let actor = player
+            .actor
+            .actor();
+        let facing = player
+            .facing
+            .map_or(Vec2::ZERO, |facing| facing.direction);
+        for (entity, interactable, transform, name) in interactable_query.iter() {
+            let offset = (transform.translation - player.transform.translation).truncate();
+            if offset.length() >= INTERACT_DISTANCE {
+                continue;
             }
+            let Some(action) = interactable.available_action(actor, &clock) else {
+                continue;
+            };
+            scored.push((
+                target_score(offset, facing),
+                InteractionCandidate {
+                    target: entity,
+                    action,
+                    label: interactable.actions[action]
+                        .label
+                        .clone(),
+                    name: name.to_string(),
+                },
+            ));
         }
-        // 인터랙션 가능한 대상이 없음
-        else {
-            for (mut visibility, _, _) in interaction_hint.iter_mut() {
-                if *visibility == Visibility::Visible {
-                    *visibility = Visibility::Hidden;
-                }
-            }
+    }
+    scored.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    let candidates: Vec<InteractionCandidate> = scored
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect();
 
-            for (_, mut interact_target) in interaction_target_query.iter_mut() {
-                interact_target.is_interactable = false;
-                interact_target.target = Entity::PLACEHOLDER;
-            }
-        }
+    // 목록이 같으면 건드리지 않아서 힌트를 매 틱 다시 쓰지 않음
+    if targets.candidates != candidates {
+        targets.replace(candidates);
+    }
+}
+
+// 다음 대상 키(기본 Q)로 주변의 상호작용 대상을 돌아가며 고름
+pub fn cycle_interaction_target(actions: ActionInput, mut targets: ResMut<InteractionTargets>) {
+    if actions.just_pressed(Action::CycleTarget)
+        && targets
+            .candidates
+            .len()
+            > 1
+    {
+        targets.cycle();
     }
 }
 
 pub fn dead_player(
     query: Query<(Entity, &Person), With<Player>>,
-    mut pop_up_ui: Query<(&mut Visibility, &mut Text, &PopUpUI)>,
+    mut pop_up_ui: Query<(&mut Visibility, &mut Text, &PopUpUI), Without<IncidentPopUp>>,
 ) {
     for (_, person) in query.iter() {
         if person.is_dead {
@@ -0,0 +1,294 @@
+use std::fmt;
+
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::clock::DayStarted;
+use crate::components::Person;
+use crate::dialogue::DialogueEnded;
+use crate::npc::Chatted;
+use crate::player::Player;
+use crate::tasks::TaskHelped;
+
+/// 관계 수치의 최댓값. 최솟값은 -RELATIONSHIP_MAX
+pub const RELATIONSHIP_MAX: i32 = 100;
+/// 하루가 지날 때마다 0 쪽으로 돌아가는 양
+const DAILY_DECAY: i32 = 1;
+/// 대화를 마치면 서로에게 오르는 호감과 신뢰
+const CONVERSATION_AFFINITY: i32 = 5;
+const CONVERSATION_TRUST: i32 = 3;
+/// 업무를 도와주면 도움받은 사람에게 오르는 호감과 신뢰
+const HELP_AFFINITY: i32 = 10;
+const HELP_TRUST: i32 = 10;
+/// 잡담하면 서로에게 오르는 호감
+const CHAT_AFFINITY: i32 = 3;
+/// 잡담에서 들은 평판은 자기 생각과의 차이를 이만큼 나눈 만큼 따라감
+const GOSSIP_DIVISOR: i32 = 5;
+
+/// 관계 수치의 종류
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum RelationshipKind {
+    /// 호감
+    Affinity,
+    /// 신뢰. 높으면 비밀을 털어놓습니다.
+    Trust,
+    /// 경쟁심
+    Rivalry,
+}
+
+impl fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RelationshipKind::Affinity => "호감",
+            RelationshipKind::Trust => "신뢰",
+            RelationshipKind::Rivalry => "경쟁심",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 한 사람이 다른 한 사람을 어떻게 생각하는지 (-100 ~ 100)
+#[derive(Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct Relationship {
+    pub other: Entity,
+    pub affinity: i32,
+    pub trust: i32,
+    pub rivalry: i32,
+}
+
+impl Relationship {
+    fn new(other: Entity) -> Self {
+        Relationship {
+            other,
+            affinity: 0,
+            trust: 0,
+            rivalry: 0,
+        }
+    }
+
+    pub fn value(&self, kind: RelationshipKind) -> i32 {
+        match kind {
+            RelationshipKind::Affinity => self.affinity,
+            RelationshipKind::Trust => self.trust,
+            RelationshipKind::Rivalry => self.rivalry,
+        }
+    }
+
+    fn value_mut(&mut self, kind: RelationshipKind) -> &mut i32 {
+        match kind {
+            RelationshipKind::Affinity => &mut self.affinity,
+            RelationshipKind::Trust => &mut self.trust,
+            RelationshipKind::Rivalry => &mut self.rivalry,
+        }
+    }
+
+    fn is_neutral(&self) -> bool {
+        self.affinity == 0 && self.trust == 0 && self.rivalry == 0
+    }
+}
+
+/// 다른 사람들에 대한 생각. 서로의 생각은 다를 수 있고, 모르는 사람은 모두 0입니다.
+#[derive(Reflect, Component, Default, Debug, Clone)]
+#[reflect(Component, MapEntities)]
+pub struct Relationships {
+    pub peers: Vec<Relationship>,
+}
+
+impl Relationships {
+    pub fn get(&self, other: Entity) -> Option<&Relationship> {
+        self.peers
+            .iter()
+            .find(|peer| peer.other == other)
+    }
+
+    pub fn value(&self, other: Entity, kind: RelationshipKind) -> i32 {
+        self.get(other)
+            .map_or(0, |peer| peer.value(kind))
+    }
+
+    pub fn change(&mut self, other: Entity, kind: RelationshipKind, amount: i32) {
+        let index = match self
+            .peers
+            .iter()
+            .position(|peer| peer.other == other)
+        {
+            Some(index) => index,
+            None => {
+                self.peers
+                    .push(Relationship::new(other));
+                self.peers.len() - 1
+            }
+        };
+        let value = self.peers[index].value_mut(kind);
+        *value = (*value + amount).clamp(-RELATIONSHIP_MAX, RELATIONSHIP_MAX);
+    }
+
+    /// 모든 수치가 0 쪽으로 조금씩 돌아가고, 잊어버린 사람은 지움
+    fn decay(&mut self) {
+        for peer in self
+            .peers
+            .iter_mut()
+        {
+            for value in [&mut peer.affinity, &mut peer.trust, &mut peer.rivalry] {
+                *value -= value.signum() * DAILY_DECAY.min(value.abs());
+            }
+        }
+        self.peers
+            .retain(|peer| !peer.is_neutral());
+    }
+}
+
+impl MapEntities for Relationships {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for peer in self
+            .peers
+            .iter_mut()
+        {
+            peer.other = entity_mapper.map_entity(peer.other);
+        }
+    }
+}
+
+/// 다른 사람들이 이 사람에게 가진 호감의 평균 (0 ~ 1). 싫어하는 사람이 많으면 0
+pub fn reputation<'a>(target: Entity, people: impl Iterator<Item = (Entity, &'a Relationships)>) -> f32 {
+    let (total, count) = people
+        .filter(|(entity, _)| *entity != target)
+        .fold((0, 0), |(total, count), (_, relationships)| {
+            (total + relationships.value(target, RelationshipKind::Affinity), count + 1)
+        });
+    if count == 0 {
+        return 0.0;
+    }
+    (total as f32 / count as f32 / RELATIONSHIP_MAX as f32).clamp(0.0, 1.0)
+}
+
+/// `entity`가 `other`를 생각하는 수치를 바꿈
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AdjustRelationship {
+    pub entity: Entity,
+    pub other: Entity,
+    pub kind: RelationshipKind,
+    pub amount: i32,
+}
+
+pub struct RelationshipPlugin;
+
+impl Plugin for RelationshipPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AdjustRelationship>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    add_relationships,
+                    remember_conversations,
+                    remember_help,
+                    gossip,
+                    adjust_relationships,
+                    decay_relationships,
+                )
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 관계가 없는 사람에게 빈 관계를 줌 (새로 생성되거나 예전 저장 파일에서 불러온 경우)
+fn add_relationships(mut commands: Commands, q_people: Query<Entity, (With<Person>, Without<Relationships>)>) {
+    for entity in q_people.iter() {
+        commands
+            .entity(entity)
+            .insert(Relationships::default());
+    }
+}
+
+// 대화를 마치면 플레이어와 상대가 서로 조금 더 친해짐
+fn remember_conversations(
+    mut dialogue_ended: EventReader<DialogueEnded>,
+    q_player: Query<Entity, With<Player>>,
+    mut adjust: EventWriter<AdjustRelationship>,
+) {
+    for event in dialogue_ended.read() {
+        let Ok(player) = q_player.get_single() else {
+            continue;
+        };
+        for (entity, other) in [(player, event.partner), (event.partner, player)] {
+            adjust.send(AdjustRelationship {
+                entity,
+                other,
+                kind: RelationshipKind::Affinity,
+                amount: CONVERSATION_AFFINITY,
+            });
+            adjust.send(AdjustRelationship {
+                entity,
+                other,
+                kind: RelationshipKind::Trust,
+                amount: CONVERSATION_TRUST,
+            });
+        }
+    }
+}
+
+// 업무를 도와준 사람을 좋아하고 믿게 됨
+fn remember_help(mut task_helped: EventReader<TaskHelped>, mut adjust: EventWriter<AdjustRelationship>) {
+    for event in task_helped.read() {
+        adjust.send(AdjustRelationship {
+            entity: event.worker,
+            other: event.helper,
+            kind: RelationshipKind::Affinity,
+            amount: HELP_AFFINITY,
+        });
+        adjust.send(AdjustRelationship {
+            entity: event.worker,
+            other: event.helper,
+            kind: RelationshipKind::Trust,
+            amount: HELP_TRUST,
+        });
+    }
+}
+
+// 잡담하면 서로 친해지고, 듣는 사람은 말하는 사람이 다른 사람들을 어떻게 생각하는지 따라감
+fn gossip(mut chatted: EventReader<Chatted>, mut q_relationships: Query<&mut Relationships>) {
+    for event in chatted.read() {
+        let Ok([mut speaker, mut listener]) = q_relationships.get_many_mut([event.speaker, event.listener]) else {
+            continue;
+        };
+        speaker.change(event.listener, RelationshipKind::Affinity, CHAT_AFFINITY);
+        listener.change(event.speaker, RelationshipKind::Affinity, CHAT_AFFINITY);
+
+        for opinion in speaker
+            .peers
+            .iter()
+            .filter(|peer| peer.other != event.listener)
+        {
+            let heard = opinion.affinity - listener.value(opinion.other, RelationshipKind::Affinity);
+            if heard / GOSSIP_DIVISOR != 0 {
+                listener.change(opinion.other, RelationshipKind::Affinity, heard / GOSSIP_DIVISOR);
+            }
+        }
+    }
+}
+
+fn adjust_relationships(
+    mut events: EventReader<AdjustRelationship>,
+    mut q_relationships: Query<&mut Relationships>,
+) {
+    for event in events.read() {
+        if event.entity == event.other {
+            continue;
+        }
+        if let Ok(mut relationships) = q_relationships.get_mut(event.entity) {
+            relationships.change(event.other, event.kind, event.amount);
+        }
+    }
+}
+
+// 하루가 지나면 관계가 조금씩 무뎌짐
+fn decay_relationships(mut day_started: EventReader<DayStarted>, mut q_relationships: Query<&mut Relationships>) {
+    for _ in day_started.read() {
+        for mut relationships in q_relationships.iter_mut() {
+            relationships.decay();
+        }
+    }
+}
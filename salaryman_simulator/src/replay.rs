@@ -0,0 +1,565 @@
+use std::fmt;
+use std::fs;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use board_plugin::resources::TileMap;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::actions::{ActionMap, MoveStick};
+use crate::clock::GameClock;
+use crate::components::{MouseInput, Person};
+use crate::dialogue::DialogueRunner;
+use crate::tasks::Working;
+use crate::tick::{SimTick, TickInput, TransformInterpolation};
+
+/// 입력 기록 파일 버전. 기록 형식이 바뀌면 올립니다.
+pub const REPLAY_VERSION: u32 = 1;
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    #[error("Could not access replay file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse replay file: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("Could not write replay file: {0}")]
+    Serialize(#[from] ron::Error),
+    #[error("Replay file version {0} is not supported (expected {REPLAY_VERSION})")]
+    UnsupportedVersion(u32),
+}
+
+/// 한 틱 동안 바뀐 입력. 아무것도 바뀌지 않은 틱은 기록하지 않습니다.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct InputFrame {
+    pub tick: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pressed: Vec<KeyCode>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub released: Vec<KeyCode>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mouse_pressed: Vec<MouseButton>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mouse_released: Vec<MouseButton>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gamepad_pressed: Vec<GamepadButtonType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gamepad_released: Vec<GamepadButtonType>,
+    /// 이 틱부터의 왼쪽 스틱 기울기 (데드존 적용 후)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stick: Option<Vec2>,
+    /// 이 틱부터의 커서 월드 위치
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<Vec2>,
+    /// 기록하는 도중에 바꾼 키 설정
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<ActionMap>,
+}
+
+impl InputFrame {
+    fn is_empty(&self) -> bool {
+        self.pressed
+            .is_empty()
+            && self
+                .released
+                .is_empty()
+            && self
+                .mouse_pressed
+                .is_empty()
+            && self
+                .mouse_released
+                .is_empty()
+            && self
+                .gamepad_pressed
+                .is_empty()
+            && self
+                .gamepad_released
+                .is_empty()
+            && self
+                .stick
+                .is_none()
+            && self
+                .cursor
+                .is_none()
+            && self
+                .bindings
+                .is_none()
+    }
+}
+
+/// 기록이 끝났을 때의 사람 상태
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PersonSnapshot {
+    pub name: String,
+    pub position: Vec2,
+    pub hp: i32,
+    pub san: i32,
+    pub is_dead: bool,
+    pub working: bool,
+}
+
+impl fmt::Display for PersonSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {}) 체력 {} 정신력 {} 사망 {} 작업 {}",
+            self.name, self.position.x, self.position.y, self.hp, self.san, self.is_dead, self.working
+        )
+    }
+}
+
+/// 재생 결과를 확인할 월드 상태
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WorldSnapshot {
+    pub clock_seconds: f64,
+    /// 진행 중인 대화의 대사 이름
+    pub dialogue: Option<String>,
+    /// 이름 순서
+    pub people: Vec<PersonSnapshot>,
+}
+
+impl WorldSnapshot {
+    pub fn capture(world: &mut World) -> Self {
+        let mut people: Vec<PersonSnapshot> = world
+            .query::<(&Name, &Transform, &Person, Has<Working>, Option<&TransformInterpolation>)>()
+            .iter(world)
+            .map(|(name, transform, person, working, interpolation)| {
+                // 화면용으로 보간된 위치가 아니라 마지막 틱의 위치
+                let translation = interpolation
+                    .map(|interpolation| interpolation.tick_translation())
+                    .unwrap_or(transform.translation);
+                PersonSnapshot {
+                    name: name.to_string(),
+                    position: translation.truncate(),
+                    hp: person.hp,
+                    san: person.san,
+                    is_dead: person.is_dead,
+                    working,
+                }
+            })
+            .collect();
+        people.sort_by(|a, b| a.name.cmp(&b.name));
+
+        WorldSnapshot {
+            clock_seconds: world
+                .resource::<GameClock>()
+                .elapsed_seconds,
+            dialogue: world
+                .resource::<DialogueRunner>()
+                .node
+                .clone(),
+            people,
+        }
+    }
+
+    /// 기대한 상태와 다른 점들
+    pub fn differences(&self, actual: &WorldSnapshot) -> Vec<String> {
+        let mut differences = Vec::new();
+        if self.clock_seconds != actual.clock_seconds {
+            differences.push(format!("게임 시간: {} != {}", self.clock_seconds, actual.clock_seconds));
+        }
+        if self.dialogue != actual.dialogue {
+            differences.push(format!("대화: {:?} != {:?}", self.dialogue, actual.dialogue));
+        }
+        for expected in self
+            .people
+            .iter()
+        {
+            match actual
+                .people
+                .iter()
+                .find(|person| person.name == expected.name)
+            {
+                Some(person) if person == expected => {}
+                Some(person) => differences.push(format!("{} != {}", expected, person)),
+                None => differences.push(format!("{}: 없음", expected.name)),
+            }
+        }
+        for person in actual
+            .people
+            .iter()
+            .filter(|person| {
+                !self
+                    .people
+                    .iter()
+                    .any(|expected| expected.name == person.name)
+            })
+        {
+            differences.push(format!("{}: 기록에 없음", person.name));
+        }
+        differences
+    }
+}
+
+/// 입력 기록 파일. 시작 조건과 틱별 입력, 끝났을 때의 상태를 담습니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputRecording {
+    pub version: u32,
+    pub seed: u64,
+    pub tick_rate: f64,
+    pub time_scale: f32,
+    pub layout_path: String,
+    /// 기록을 시작할 때의 키 설정. 재생할 때는 사용자의 키 설정 대신 이것을 씁니다.
+    #[serde(default)]
+    pub bindings: ActionMap,
+    /// 기록한 틱 수
+    #[serde(default)]
+    pub ticks: u64,
+    #[serde(default)]
+    pub frames: Vec<InputFrame>,
+    #[serde(default)]
+    pub final_state: Option<WorldSnapshot>,
+}
+
+impl InputRecording {
+    pub fn new(seed: u64, tick_rate: f64, time_scale: f32, layout_path: String, bindings: ActionMap) -> Self {
+        InputRecording {
+            version: REPLAY_VERSION,
+            seed,
+            tick_rate,
+            time_scale,
+            layout_path,
+            bindings,
+            ticks: 0,
+            frames: Vec::new(),
+            final_state: None,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        let text = fs::read_to_string(path)?;
+        let recording: InputRecording = ron::de::from_str(&text)?;
+        if recording.version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(recording.version));
+        }
+        Ok(recording)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        if let Some(directory) = path
+            .parent()
+            .filter(|directory| !directory.as_os_str().is_empty())
+        {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+/// 틱마다 입력을 기록하다가 종료할 때 파일로 저장
+#[derive(Resource, Debug)]
+pub struct InputRecorder {
+    path: PathBuf,
+    recording: InputRecording,
+    /// 마지막으로 기록한 키 설정
+    bindings: ActionMap,
+    /// 마지막으로 기록한 스틱 기울기
+    stick: Vec2,
+    /// 마지막으로 기록한 커서 위치
+    cursor: Option<Vec2>,
+}
+
+impl InputRecorder {
+    pub fn new(path: PathBuf, recording: InputRecording) -> Self {
+        let bindings = recording
+            .bindings
+            .clone();
+        InputRecorder {
+            path,
+            recording,
+            bindings,
+            stick: Vec2::ZERO,
+            cursor: None,
+        }
+    }
+}
+
+/// 기록된 입력을 틱마다 TickInput에 넣음. 재생하는 동안 실제 입력은 무시합니다.
+#[derive(Resource, Debug)]
+pub struct InputReplay {
+    recording: InputRecording,
+    next_frame: usize,
+    keys: ButtonInput<KeyCode>,
+    mouse_buttons: ButtonInput<MouseButton>,
+    gamepad_buttons: ButtonInput<GamepadButtonType>,
+    stick: Vec2,
+}
+
+impl InputReplay {
+    pub fn new(recording: InputRecording) -> Self {
+        InputReplay {
+            recording,
+            next_frame: 0,
+            keys: ButtonInput::default(),
+            mouse_buttons: ButtonInput::default(),
+            gamepad_buttons: ButtonInput::default(),
+            stick: Vec2::ZERO,
+        }
+    }
+
+    /// 기록된 틱 수
+    pub fn ticks(&self) -> u64 {
+        self.recording.ticks
+    }
+}
+
+/// 재생이 끝난 뒤 기록된 최종 상태와 비교한 결과. 다른 점이 없으면 통과입니다.
+#[derive(Resource, Debug, Clone)]
+pub struct ReplayOutcome {
+    pub ticks: u64,
+    /// 기록에 최종 상태가 없으면 비교하지 않음
+    pub differences: Option<Vec<String>>,
+}
+
+impl ReplayOutcome {
+    pub fn passed(&self) -> bool {
+        self.differences
+            .as_ref()
+            .is_none_or(|differences| differences.is_empty())
+    }
+}
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        // 배치도가 로드되기 전의 틱은 세지 않으므로 기록과 재생도 그 뒤부터
+        app.add_systems(
+            FixedPreUpdate,
+            (
+                finish_replay.run_if(resource_exists::<InputReplay>),
+                replay_input.run_if(resource_exists::<InputReplay>),
+                record_input.run_if(resource_exists::<InputRecorder>),
+            )
+                .chain()
+                .run_if(resource_exists::<TileMap>),
+        )
+        .add_systems(Last, finish_recording_on_exit.run_if(resource_exists::<InputRecorder>));
+    }
+}
+
+// 기록한 틱 수만큼 재생했으면 기록된 최종 상태와 비교하고 실제 입력으로 돌아감
+fn finish_replay(world: &mut World) {
+    let tick = world
+        .resource::<SimTick>()
+        .0;
+    let replay = world.resource::<InputReplay>();
+    if tick < replay.recording.ticks {
+        return;
+    }
+    let expected = replay
+        .recording
+        .final_state
+        .clone();
+    world.remove_resource::<InputReplay>();
+
+    let actual = WorldSnapshot::capture(world);
+    let outcome = ReplayOutcome {
+        ticks: tick,
+        differences: expected.map(|expected| expected.differences(&actual)),
+    };
+    match &outcome.differences {
+        None => info!("재생 끝 ({} 틱, 최종 상태 기록 없음)", tick),
+        Some(differences) if differences.is_empty() => info!("재생 끝 ({} 틱): 최종 상태 일치", tick),
+        Some(differences) => {
+            for difference in differences.iter() {
+                error!("재생 결과가 기록과 다름: {}", difference);
+            }
+        }
+    }
+    world.insert_resource(outcome);
+
+    // 재생하면서 기록했으면 같은 틱에서 기록도 끝냄
+    match finish_recording(world) {
+        Ok(Some(path)) => info!("Saved input recording to {}", path.display()),
+        Ok(None) => {}
+        Err(error) => error!("Could not save input recording: {}", error),
+    }
+}
+
+// 이번 틱에 기록된 입력을 실제 입력 대신 넣음
+fn replay_input(
+    tick: Res<SimTick>,
+    mut replay: ResMut<InputReplay>,
+    mut keys: ResMut<TickInput<KeyCode>>,
+    mut mouse_buttons: ResMut<TickInput<MouseButton>>,
+    (mut gamepad_buttons, mut stick): (ResMut<TickInput<GamepadButtonType>>, ResMut<MoveStick>),
+    mut action_map: ResMut<ActionMap>,
+    mut q_mouse_inputs: Query<&mut MouseInput>,
+) {
+    let replay = replay.as_mut();
+    replay
+        .keys
+        .clear();
+    replay
+        .mouse_buttons
+        .clear();
+    replay
+        .gamepad_buttons
+        .clear();
+
+    while let Some(frame) = replay
+        .recording
+        .frames
+        .get(replay.next_frame)
+        .filter(|frame| frame.tick <= tick.0)
+    {
+        apply_buttons(&mut replay.keys, &frame.pressed, &frame.released);
+        apply_buttons(&mut replay.mouse_buttons, &frame.mouse_pressed, &frame.mouse_released);
+        apply_buttons(&mut replay.gamepad_buttons, &frame.gamepad_pressed, &frame.gamepad_released);
+        if let Some(tilt) = frame.stick {
+            replay.stick = tilt;
+        }
+        if let Some(bindings) = &frame.bindings {
+            *action_map = bindings.clone();
+        }
+        if let Some(cursor) = frame.cursor {
+            for mut mouse_input in q_mouse_inputs.iter_mut() {
+                mouse_input.world_position = cursor;
+            }
+        }
+        replay.next_frame += 1;
+    }
+
+    **keys = replay
+        .keys
+        .clone();
+    **mouse_buttons = replay
+        .mouse_buttons
+        .clone();
+    **gamepad_buttons = replay
+        .gamepad_buttons
+        .clone();
+    stick.0 = replay.stick;
+}
+
+/// 한 틱 안에서 누르고 뗀 순서를 틱 전의 상태로 되살림
+fn apply_buttons<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    state: &mut ButtonInput<T>,
+    pressed: &[T],
+    released: &[T],
+) {
+    // 틱 전부터 누르고 있던 버튼은 뗀 다음 다시 누른 것, 아니면 누른 다음 뗀 것
+    let held: Vec<T> = pressed
+        .iter()
+        .chain(released)
+        .filter(|button| state.pressed(**button))
+        .copied()
+        .collect();
+    for button in released
+        .iter()
+        .filter(|button| held.contains(button))
+    {
+        state.release(*button);
+    }
+    for button in pressed {
+        state.press(*button);
+    }
+    for button in released
+        .iter()
+        .filter(|button| !held.contains(button))
+    {
+        state.release(*button);
+    }
+}
+
+// 이번 틱에 바뀐 입력, 스틱 기울기, 커서 위치와 키 설정을 기록
+fn record_input(
+    tick: Res<SimTick>,
+    keys: Res<TickInput<KeyCode>>,
+    mouse_buttons: Res<TickInput<MouseButton>>,
+    (gamepad_buttons, stick): (Res<TickInput<GamepadButtonType>>, Res<MoveStick>),
+    action_map: Res<ActionMap>,
+    q_mouse_inputs: Query<&MouseInput>,
+    mut recorder: ResMut<InputRecorder>,
+) {
+    let mut frame = InputFrame {
+        tick: tick.0,
+        pressed: keys
+            .get_just_pressed()
+            .copied()
+            .collect(),
+        released: keys
+            .get_just_released()
+            .copied()
+            .collect(),
+        mouse_pressed: mouse_buttons
+            .get_just_pressed()
+            .copied()
+            .collect(),
+        mouse_released: mouse_buttons
+            .get_just_released()
+            .copied()
+            .collect(),
+        gamepad_pressed: gamepad_buttons
+            .get_just_pressed()
+            .copied()
+            .collect(),
+        gamepad_released: gamepad_buttons
+            .get_just_released()
+            .copied()
+            .collect(),
+        stick: None,
+        cursor: None,
+        bindings: None,
+    };
+    if stick.0 != recorder.stick {
+        recorder.stick = stick.0;
+        frame.stick = Some(stick.0);
+    }
+    // 버튼을 누르지 않아도 커서가 가리키는 물체가 바뀌므로 움직인 틱마다 남김
+    let cursor = q_mouse_inputs
+        .get_single()
+        .ok()
+        .map(|mouse_input| mouse_input.world_position);
+    if cursor.is_some() && cursor != recorder.cursor {
+        recorder.cursor = cursor;
+        frame.cursor = cursor;
+    }
+    if *action_map != recorder.bindings {
+        recorder.bindings = action_map.clone();
+        frame.bindings = Some(action_map.clone());
+    }
+    if frame.is_empty() {
+        return;
+    }
+    recorder
+        .recording
+        .frames
+        .push(frame);
+}
+
+/// 지금까지의 입력과 최종 상태를 기록 파일로 저장
+pub fn finish_recording(world: &mut World) -> Result<Option<PathBuf>, ReplayError> {
+    let Some(mut recorder) = world.remove_resource::<InputRecorder>() else {
+        return Ok(None);
+    };
+    recorder.recording.ticks = world
+        .resource::<SimTick>()
+        .0;
+    recorder.recording.final_state = Some(WorldSnapshot::capture(world));
+    recorder
+        .recording
+        .save(&recorder.path)?;
+    Ok(Some(recorder.path))
+}
+
+// 창을 닫으면 기록을 저장
+fn finish_recording_on_exit(world: &mut World) {
+    if world
+        .resource::<Events<AppExit>>()
+        .is_empty()
+    {
+        return;
+    }
+    match finish_recording(world) {
+        Ok(Some(path)) => info!("Saved input recording to {}", path.display()),
+        Ok(None) => {}
+        Err(error) => error!("Could not save input recording: {}", error),
+    }
+}
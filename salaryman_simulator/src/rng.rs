@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::components::Person;
+
+/// 시뮬레이션 난수
+///
+/// 무작위 결정은 모두 이 시드에서 나오므로 같은 시드로 시작하면 같은 게임이 재현됩니다.
+/// 시드와 지금까지 뽑은 위치는 저장 파일에도 들어가고, 불러오면 저장한 곳부터 이어서 뽑습니다.
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource, from_reflect = false)]
+pub struct SimRng {
+    seed: u64,
+    /// 저장할 때 기록한 난수의 위치 (ChaCha 워드 단위)
+    word_pos: u64,
+    /// 저장할 때 기록한 이름별 스트림의 위치. 불러온 뒤 스트림을 다시 만들 때 한 번 씁니다.
+    stream_positions: HashMap<String, u64>,
+    #[reflect(ignore)]
+    rng: ChaCha8Rng,
+}
+
+impl Default for SimRng {
+    /// 시드를 정하지 않으면 무작위 시드
+    fn default() -> Self {
+        SimRng::new(rand::random())
+    }
+}
+
+impl SimRng {
+    pub fn new(seed: u64) -> Self {
+        SimRng {
+            seed,
+            word_pos: 0,
+            stream_positions: HashMap::new(),
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// 시드를 그대로 두고 기록한 위치부터 다시 뽑음. 불러온 뒤에 씁니다.
+    pub fn reseed(&mut self) {
+        self.rng = ChaCha8Rng::seed_from_u64(self.seed);
+        self.rng
+            .set_word_pos(self.word_pos as u128);
+    }
+
+    /// 저장하기 전에 지금 위치와 스트림들의 위치를 기록
+    pub fn record_positions(&mut self, streams: impl IntoIterator<Item = (String, u64)>) {
+        self.word_pos = self
+            .rng
+            .get_word_pos() as u64;
+        self.stream_positions = streams
+            .into_iter()
+            .collect();
+    }
+
+    /// 저장이 끝나면 기록한 스트림 위치를 지움. 남겨 두면 나중에 들어온 같은 이름의 사람이 이어받음
+    pub fn clear_stream_positions(&mut self) {
+        self.stream_positions
+            .clear();
+    }
+
+    /// 저장 파일에서 불러온 사람의 스트림. 기록한 위치가 있으면 거기서부터 이어서 뽑음
+    pub fn resume(&mut self, name: &str) -> RngStream {
+        let mut stream = self.fork(name);
+        if let Some(word_pos) = self
+            .stream_positions
+            .remove(name)
+        {
+            stream
+                .0
+                .set_word_pos(word_pos as u128);
+        }
+        stream
+    }
+
+    /// 이름이 붙은 독립 스트림. 다른 스트림을 얼마나 썼는지와 상관없이 시드와 이름이 같으면 같은 수열입니다.
+    pub fn fork(&self, name: &str) -> RngStream {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        rng.set_stream(stream_id(name));
+        RngStream(rng)
+    }
+}
+
+impl RngCore for SimRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng
+            .next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng
+            .next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng
+            .fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng
+            .try_fill_bytes(dest)
+    }
+}
+
+/// 사람마다 따로 쓰는 난수 스트림
+///
+/// 이름으로 갈라지므로 동료가 새로 들어와도 다른 사람이 뽑는 수는 바뀌지 않습니다.
+#[derive(Component, Debug, Clone)]
+pub struct RngStream(ChaCha8Rng);
+
+impl RngStream {
+    /// 지금까지 뽑은 위치 (ChaCha 워드 단위)
+    pub fn word_pos(&self) -> u64 {
+        self.0
+            .get_word_pos() as u64
+    }
+}
+
+impl RngCore for RngStream {
+    fn next_u32(&mut self) -> u32 {
+        self.0
+            .next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0
+            .next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0
+            .fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0
+            .try_fill_bytes(dest)
+    }
+}
+
+/// 스트림 이름을 스트림 번호로 바꿈. 실행할 때마다 같아야 하므로 FNV-1a 해시를 씁니다.
+fn stream_id(name: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    name.bytes()
+        .fold(OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+pub struct RngPlugin;
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SimRng>()
+            .add_systems(Startup, log_seed)
+            .add_systems(
+                FixedUpdate,
+                add_rng_streams.before(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 버그 제보에 적을 수 있도록 시드를 남김
+fn log_seed(sim_rng: Res<SimRng>) {
+    info!("시뮬레이션 시드: {} (--seed {}로 재현)", sim_rng.seed(), sim_rng.seed());
+}
+
+// 스트림이 없는 사람에게 이름으로 갈라진 스트림을 줌 (새로 생성되거나 저장 파일에서 불러온 경우)
+fn add_rng_streams(
+    mut commands: Commands,
+    mut sim_rng: ResMut<SimRng>,
+    q_people: Query<(Entity, &Name, Has<RngStream>), With<Person>>,
+) {
+    for (entity, name, _) in q_people
+        .iter()
+        .filter(|(_, _, has_stream)| !has_stream)
+    {
+        commands
+            .entity(entity)
+            .insert(sim_rng.resume(name.as_str()));
+    }
+}
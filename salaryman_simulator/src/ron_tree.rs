@@ -0,0 +1,421 @@
+use std::fmt;
+
+use thiserror::Error;
+
+/// 저장 파일을 변환할 때 쓰는 RON 트리
+///
+/// `ron::Value`는 열거형 이름과 구조체 이름을 버리므로, 읽은 그대로 다시 쓸 수 있도록 값의 모양을 보존합니다.
+/// 줄바꿈이나 들여쓰기와 상관없이 같은 트리가 됩니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RonNode {
+    /// 숫자, 문자열, 불, 유닛 열거형 값처럼 더 나누지 않는 값. 원문 그대로 가짐
+    Atom(String),
+    /// `이름(값, ...)` 또는 `(값, ...)`. `Some(..)`, 튜플 열거형, 튜플 구조체
+    Tuple(Option<String>, Vec<RonNode>),
+    /// `이름(필드: 값, ...)` 또는 `(필드: 값, ...)`
+    Struct(Option<String>, Vec<(String, RonNode)>),
+    List(Vec<RonNode>),
+    Map(Vec<(RonNode, RonNode)>),
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum RonTreeError {
+    #[error("Unexpected end of RON text")]
+    UnexpectedEnd,
+    #[error("Unexpected character {1:?} at byte {0}")]
+    UnexpectedChar(usize, char),
+}
+
+impl RonNode {
+    pub fn parse(text: &str) -> Result<RonNode, RonTreeError> {
+        let mut parser = Parser { text, position: 0 };
+        let node = parser.value()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(node),
+            Some(c) => Err(RonTreeError::UnexpectedChar(parser.position, c)),
+        }
+    }
+
+    pub fn atom(value: impl ToString) -> RonNode {
+        RonNode::Atom(value.to_string())
+    }
+
+    /// 이름 없는 구조체
+    pub fn fields(fields: Vec<(&str, RonNode)>) -> RonNode {
+        RonNode::Struct(
+            None,
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            RonNode::Atom(atom) => atom
+                .parse()
+                .ok(),
+            _ => None,
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<&RonNode> {
+        match self {
+            RonNode::Struct(_, fields) => fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut RonNode> {
+        match self {
+            RonNode::Struct(_, fields) => fields
+                .iter_mut()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn remove_field(&mut self, name: &str) -> Option<RonNode> {
+        let RonNode::Struct(_, fields) = self else {
+            return None;
+        };
+        let index = fields
+            .iter()
+            .position(|(field, _)| field == name)?;
+        Some(
+            fields
+                .remove(index)
+                .1,
+        )
+    }
+
+    /// 필드를 넣거나 바꿈. 필드가 없는 `()`는 구조체로 봅니다.
+    pub fn insert_field(&mut self, name: &str, value: RonNode) {
+        if let RonNode::Tuple(type_name, values) = self {
+            if values.is_empty() {
+                *self = RonNode::Struct(type_name.take(), Vec::new());
+            }
+        }
+        let RonNode::Struct(_, fields) = self else {
+            return;
+        };
+        match fields
+            .iter_mut()
+            .find(|(field, _)| field == name)
+        {
+            Some((_, old)) => *old = value,
+            None => fields.push((name.to_string(), value)),
+        }
+    }
+
+    /// 문자열 키로 맵의 값을 찾음
+    pub fn entry_mut(&mut self, key: &str) -> Option<&mut RonNode> {
+        match self {
+            RonNode::Map(entries) => entries
+                .iter_mut()
+                .find(|(entry_key, _)| entry_key.is_string(key))
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// 문자열 키로 맵에 값을 넣거나 바꿈
+    pub fn insert_entry(&mut self, key: &str, value: RonNode) {
+        let RonNode::Map(entries) = self else {
+            return;
+        };
+        match entries
+            .iter_mut()
+            .find(|(entry_key, _)| entry_key.is_string(key))
+        {
+            Some((_, old)) => *old = value,
+            None => entries.push((RonNode::Atom(format!("{:?}", key)), value)),
+        }
+    }
+
+    /// 맵의 값들
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut RonNode> {
+        let entries: &mut [(RonNode, RonNode)] = match self {
+            RonNode::Map(entries) => entries,
+            _ => &mut [],
+        };
+        entries
+            .iter_mut()
+            .map(|(_, value)| value)
+    }
+
+    pub fn items_mut(&mut self) -> impl Iterator<Item = &mut RonNode> {
+        let items: &mut [RonNode] = match self {
+            RonNode::List(items) => items,
+            _ => &mut [],
+        };
+        items.iter_mut()
+    }
+
+    fn is_string(&self, value: &str) -> bool {
+        matches!(self, RonNode::Atom(atom) if atom.strip_prefix('"').and_then(|atom| atom.strip_suffix('"')) == Some(value))
+    }
+}
+
+impl fmt::Display for RonNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RonNode::Atom(atom) => write!(f, "{}", atom),
+            RonNode::Tuple(name, values) => {
+                write!(f, "{}(", name.as_deref().unwrap_or(""))?;
+                for value in values.iter() {
+                    write!(f, "{},", value)?;
+                }
+                write!(f, ")")
+            }
+            RonNode::Struct(name, fields) => {
+                write!(f, "{}(", name.as_deref().unwrap_or(""))?;
+                for (field, value) in fields.iter() {
+                    write!(f, "{}:{},", field, value)?;
+                }
+                write!(f, ")")
+            }
+            RonNode::List(items) => {
+                write!(f, "[")?;
+                for item in items.iter() {
+                    write!(f, "{},", item)?;
+                }
+                write!(f, "]")
+            }
+            RonNode::Map(entries) => {
+                write!(f, "{{")?;
+                for (key, value) in entries.iter() {
+                    write!(f, "{}:{},", key, value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..]
+            .chars()
+            .next()
+    }
+
+    fn bump(&mut self) -> Result<char, RonTreeError> {
+        let c = self
+            .peek()
+            .ok_or(RonTreeError::UnexpectedEnd)?;
+        self.position += c.len_utf8();
+        Ok(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), RonTreeError> {
+        self.skip_whitespace();
+        let position = self.position;
+        match self.bump()? {
+            c if c == expected => Ok(()),
+            c => Err(RonTreeError::UnexpectedChar(position, c)),
+        }
+    }
+
+    // 공백과 주석을 건너뜀
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = &self.text[self.position..];
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.position += trimmed
+                    .find('\n')
+                    .unwrap_or(trimmed.len());
+            } else if trimmed.starts_with("/*") {
+                self.position += trimmed
+                    .find("*/")
+                    .map_or(trimmed.len(), |end| end + 2);
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// 쉼표로 나눈 항목들을 `close`까지 읽음. 마지막 쉼표는 있어도 됨
+    fn separated<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, RonTreeError>,
+    ) -> Result<Vec<T>, RonTreeError> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(close) {
+                self.bump()?;
+                return Ok(items);
+            }
+            items.push(item(self)?);
+            self.skip_whitespace();
+            if self.peek() == Some(',') {
+                self.bump()?;
+            } else {
+                self.expect(close)?;
+                return Ok(items);
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<RonNode, RonTreeError> {
+        self.skip_whitespace();
+        let position = self.position;
+        match self
+            .peek()
+            .ok_or(RonTreeError::UnexpectedEnd)?
+        {
+            '(' => self.parenthesized(None),
+            '[' => {
+                self.bump()?;
+                Ok(RonNode::List(self.separated(']', Self::value)?))
+            }
+            '{' => {
+                self.bump()?;
+                let entries = self.separated('}', |parser| {
+                    let key = parser.value()?;
+                    parser.expect(':')?;
+                    Ok((key, parser.value()?))
+                })?;
+                Ok(RonNode::Map(entries))
+            }
+            '"' | '\'' => self.quoted(),
+            c if c.is_alphanumeric() || matches!(c, '_' | '-' | '+' | '.') => {
+                let token = self.token();
+                self.skip_whitespace();
+                let is_identifier = token.starts_with(|c: char| c.is_alphabetic() || c == '_');
+                if is_identifier && self.peek() == Some('(') {
+                    self.parenthesized(Some(token))
+                } else {
+                    Ok(RonNode::Atom(token))
+                }
+            }
+            c => Err(RonTreeError::UnexpectedChar(position, c)),
+        }
+    }
+
+    // 이름, 숫자 (지수의 부호까지)
+    fn token(&mut self) -> String {
+        let start = self.position;
+        let mut previous = ' ';
+        while let Some(c) = self.peek() {
+            let exponent_sign = matches!(c, '+' | '-') && matches!(previous, 'e' | 'E');
+            if !(c.is_alphanumeric() || matches!(c, '_' | '.') || exponent_sign || self.position == start) {
+                break;
+            }
+            previous = c;
+            self.position += c.len_utf8();
+        }
+        self.text[start..self.position].to_string()
+    }
+
+    fn quoted(&mut self) -> Result<RonNode, RonTreeError> {
+        let start = self.position;
+        let quote = self.bump()?;
+        loop {
+            match self.bump()? {
+                '\\' => {
+                    self.bump()?;
+                }
+                c if c == quote => break,
+                _ => {}
+            }
+        }
+        Ok(RonNode::Atom(self.text[start..self.position].to_string()))
+    }
+
+    // `(`부터. 첫 항목이 `이름:`이면 구조체, 아니면 튜플
+    fn parenthesized(&mut self, name: Option<String>) -> Result<RonNode, RonTreeError> {
+        self.expect('(')?;
+        self.skip_whitespace();
+        let rest = &self.text[self.position..];
+        let field_length = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let after_field = rest[field_length..].trim_start();
+        let is_struct = field_length > 0
+            && rest.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && after_field.starts_with(':')
+            && !after_field.starts_with("::");
+        if !is_struct {
+            return Ok(RonNode::Tuple(name, self.separated(')', Self::value)?));
+        }
+        let fields = self.separated(')', |parser| {
+            parser.skip_whitespace();
+            let field = parser.token();
+            parser.expect(':')?;
+            Ok((field, parser.value()?))
+        })?;
+        Ok(RonNode::Struct(name, fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_enum_and_struct_names() {
+        let text = r#"(
+            kind: SalaryMan,
+            effects: [ChangeSan(-2), Restore(Coffee)],
+            money: Money(amount: 10, currency: KRW),
+            name: Some("김 \"대리\""),
+            map: {"a::B": (1.5e-3, ()), 7: []},
+        )"#;
+        let node = RonNode::parse(text).unwrap();
+        assert_eq!(node.field("kind"), Some(&RonNode::atom("SalaryMan")));
+        assert_eq!(
+            node.field("effects"),
+            Some(&RonNode::List(vec![
+                RonNode::Tuple(Some("ChangeSan".to_string()), vec![RonNode::atom("-2")]),
+                RonNode::Tuple(Some("Restore".to_string()), vec![RonNode::atom("Coffee")]),
+            ]))
+        );
+        assert_eq!(
+            RonNode::parse(&node.to_string()),
+            Ok(node)
+        );
+    }
+
+    #[test]
+    fn layout_does_not_matter() {
+        let pretty = "(\n    resources: {\n        \"a\": (x: 1),\n    },\n)";
+        let compact = "(resources:{\"a\":(x:1)})";
+        assert_eq!(RonNode::parse(pretty), RonNode::parse(compact));
+    }
+
+    #[test]
+    fn edits_fields_and_entries() {
+        let mut node = RonNode::parse("(resources: {}, player: ())").unwrap();
+        node.field_mut("resources")
+            .unwrap()
+            .insert_entry("a::B", RonNode::atom(1));
+        node.field_mut("player")
+            .unwrap()
+            .insert_field("hp", RonNode::atom(3));
+        assert_eq!(node.to_string(), "(resources:{\"a::B\":1,},player:(hp:3,),)");
+        assert_eq!(node.remove_field("player"), Some(RonNode::fields(vec![("hp", RonNode::atom(3))])));
+    }
+
+    #[test]
+    fn reports_broken_text() {
+        assert_eq!(RonNode::parse("(a: 1"), Err(RonTreeError::UnexpectedEnd));
+        assert_eq!(RonNode::parse("(a: 1))"), Err(RonTreeError::UnexpectedChar(6, ')')));
+    }
+}
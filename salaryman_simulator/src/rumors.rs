@@ -0,0 +1,343 @@
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::clock::{DayStarted, GameClock, HourStarted};
+use crate::components::{Interactable, Person};
+use crate::npc::Chatted;
+use crate::relationships::{AdjustRelationship, RelationshipKind, Relationships, RELATIONSHIP_MAX};
+use crate::rng::RngStream;
+use crate::tasks::TaskHelped;
+
+/// 이 거리 안에 있는 동료에게 소문을 옮길 수 있음
+const RUMOR_DISTANCE: f32 = 150.0;
+/// 옆에 있는 동료에게 매시 소문을 옮길 확률. 상대를 믿을수록 높아집니다.
+const SPREAD_CHANCE: f64 = 0.2;
+const TRUST_SPREAD_CHANCE: f64 = 0.5;
+/// 옮길 때마다 소문이 부풀려질 확률
+const DISTORTION_CHANCE: f64 = 0.3;
+/// 부풀려져도 이 이상 평판을 바꾸지는 않음
+const MAX_RUMOR_EFFECT: i32 = 10;
+/// 이만큼 건너 들으면 말이 달라짐
+const EXAGGERATED_HOPS: u32 = 2;
+/// 이만큼 날이 지나면 소문이 잊힘
+const RUMOR_DAYS: u32 = 10;
+
+/// 소문의 내용
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum RumorTopic {
+    /// 주인공이 처음 소문을 낸 사람에게 연봉을 물어봄
+    AskedSalary,
+    /// 주인공이 처음 소문을 낸 사람의 업무를 도와줌
+    HelpedWithTask,
+    /// 주인공이 돕겠다고 하고선 그만둠
+    DeclinedHelp,
+    /// 주인공이 처음 소문을 낸 사람을 이기겠다고 함
+    Rivalry,
+}
+
+impl RumorTopic {
+    /// 처음 소문을 들은 사람의 주인공에 대한 호감 변화
+    pub fn affinity(&self) -> i32 {
+        match self {
+            RumorTopic::AskedSalary => -3,
+            RumorTopic::HelpedWithTask => 3,
+            RumorTopic::DeclinedHelp => -2,
+            RumorTopic::Rivalry => -1,
+        }
+    }
+
+    /// 소문 문장. 여러 번 건너 들을수록 부풀려집니다.
+    pub fn text(&self, subject: &str, source: &str, hops: u32) -> String {
+        let exaggerated = hops >= EXAGGERATED_HOPS;
+        match (self, exaggerated) {
+            (RumorTopic::AskedSalary, false) => format!("{} 씨가 {} 씨한테 연봉을 물어봤대요", subject, source),
+            (RumorTopic::AskedSalary, true) => format!("{} 씨가 사람들 연봉을 캐고 다닌대요", subject),
+            (RumorTopic::HelpedWithTask, false) => format!("{} 씨가 {} 씨 업무를 도와줬대요", subject, source),
+            (RumorTopic::HelpedWithTask, true) => format!("{} 씨가 {} 씨 일을 다 해줬대요", subject, source),
+            (RumorTopic::DeclinedHelp, false) => format!("{} 씨가 {} 씨를 도와준다더니 말았대요", subject, source),
+            (RumorTopic::DeclinedHelp, true) => format!("{} 씨는 말만 하고 안 도와준대요", subject),
+            (RumorTopic::Rivalry, false) => format!("{} 씨가 {} 씨를 따라잡겠대요", subject, source),
+            (RumorTopic::Rivalry, true) => format!("{} 씨가 {} 씨 자리를 노린대요", subject, source),
+        }
+    }
+}
+
+/// 소문을 아는 사람과 들은 그대로의 내용
+#[derive(Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct RumorKnower {
+    pub person: Entity,
+    /// 처음 소문을 낸 사람에게서 몇 번 건너 들었는지
+    pub hops: u32,
+    /// 들은 소문으로 바뀐 주인공에 대한 호감. 건너갈수록 부풀려질 수 있음
+    pub affinity: i32,
+}
+
+/// 사무실에 도는 소문. 소문 하나가 엔티티 하나입니다.
+#[derive(Reflect, Component, Debug, Clone)]
+#[reflect(Component, MapEntities)]
+pub struct Rumor {
+    pub topic: RumorTopic,
+    /// 소문의 주인공. 듣는 사람들의 이 사람에 대한 평판이 바뀝니다.
+    pub subject: Entity,
+    /// 처음 소문을 낸 사람
+    pub source: Entity,
+    /// 처음 소문을 낸 사람이 말한 그대로의 문장
+    pub content: String,
+    /// 소문이 난 날 (게임 시작일로부터 지난 날 수)
+    pub day: u32,
+    pub knowers: Vec<RumorKnower>,
+}
+
+impl Rumor {
+    pub fn knower(&self, person: Entity) -> Option<&RumorKnower> {
+        self.knowers
+            .iter()
+            .find(|knower| knower.person == person)
+    }
+
+    pub fn knows(&self, person: Entity) -> bool {
+        self.knower(person)
+            .is_some()
+    }
+
+    /// 아직 모르는 사람이 이 소문을 들을 수 있는지. 주인공에게는 말하지 않음
+    fn can_hear(&self, person: Entity) -> bool {
+        person != self.subject && !self.knows(person)
+    }
+}
+
+impl MapEntities for Rumor {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.subject = entity_mapper.map_entity(self.subject);
+        self.source = entity_mapper.map_entity(self.source);
+        for knower in self
+            .knowers
+            .iter_mut()
+        {
+            knower.person = entity_mapper.map_entity(knower.person);
+        }
+    }
+}
+
+/// `source`가 알고 있는 `subject`에 대한 소문을 냄
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StartRumor {
+    pub topic: RumorTopic,
+    pub subject: Entity,
+    pub source: Entity,
+}
+
+pub struct RumorPlugin;
+
+impl Plugin for RumorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<StartRumor>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    rumors_from_help,
+                    start_rumors,
+                    gossip_rumors,
+                    spread_rumors,
+                    forget_rumors,
+                )
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 업무를 도와주면 도움받은 사람이 소문을 냄
+fn rumors_from_help(mut task_helped: EventReader<TaskHelped>, mut start: EventWriter<StartRumor>) {
+    for event in task_helped.read() {
+        start.send(StartRumor {
+            topic: RumorTopic::HelpedWithTask,
+            subject: event.helper,
+            source: event.worker,
+        });
+    }
+}
+
+// 같은 사람이 같은 일로 낸 소문이 이미 돌고 있으면 새로 내지 않음
+fn start_rumors(
+    mut commands: Commands,
+    mut events: EventReader<StartRumor>,
+    clock: Res<GameClock>,
+    q_names: Query<&Name>,
+    q_rumors: Query<&Rumor>,
+) {
+    let mut started: Vec<StartRumor> = Vec::new();
+    for event in events.read() {
+        let already_spreading = q_rumors
+            .iter()
+            .any(|rumor| rumor.topic == event.topic && rumor.subject == event.subject && rumor.source == event.source)
+            || started
+                .iter()
+                .any(|other| other.topic == event.topic && other.subject == event.subject && other.source == event.source);
+        if event.subject == event.source || already_spreading {
+            continue;
+        }
+        let (Ok(subject), Ok(source)) = (q_names.get(event.subject), q_names.get(event.source)) else {
+            continue;
+        };
+        let content = event
+            .topic
+            .text(subject.as_str(), source.as_str(), 0);
+        info!("소문이 났습니다: {}", content);
+        commands.spawn((
+            Name::new(format!("소문: {}", content)),
+            Rumor {
+                topic: event.topic,
+                subject: event.subject,
+                source: event.source,
+                content,
+                day: clock.day(),
+                knowers: vec![RumorKnower {
+                    person: event.source,
+                    hops: 0,
+                    affinity: event
+                        .topic
+                        .affinity(),
+                }],
+            },
+        ));
+        started.push(*event);
+    }
+}
+
+/// 소문을 옮기면서 조금씩 부풀림
+fn pass_on(knower: &RumorKnower, listener: Entity, rng: &mut RngStream) -> RumorKnower {
+    let mut affinity = knower.affinity;
+    if rng.gen_bool(DISTORTION_CHANCE) {
+        affinity = (affinity + affinity.signum()).clamp(-MAX_RUMOR_EFFECT, MAX_RUMOR_EFFECT);
+    }
+    RumorKnower {
+        person: listener,
+        hops: knower.hops + 1,
+        affinity,
+    }
+}
+
+// 잡담하면 말하는 사람이 아는 소문을 모두 듣는 사람에게 옮김
+fn gossip_rumors(
+    mut chatted: EventReader<Chatted>,
+    mut q_rumors: Query<&mut Rumor>,
+    mut q_rng: Query<&mut RngStream>,
+    mut adjust: EventWriter<AdjustRelationship>,
+) {
+    for event in chatted.read() {
+        let Ok(mut rng) = q_rng.get_mut(event.speaker) else {
+            continue;
+        };
+        for mut rumor in q_rumors.iter_mut() {
+            if !rumor.can_hear(event.listener) {
+                continue;
+            }
+            let Some(heard) = rumor
+                .knower(event.speaker)
+                .map(|knower| pass_on(knower, event.listener, &mut rng))
+            else {
+                continue;
+            };
+            adjust.send(AdjustRelationship {
+                entity: event.listener,
+                other: rumor.subject,
+                kind: RelationshipKind::Affinity,
+                amount: heard.affinity,
+            });
+            rumor
+                .knowers
+                .push(heard);
+        }
+    }
+}
+
+/// 소문을 들을 수 있는 동료
+type RumorListeners<'w, 's> = Query<'w, 's, (Entity, &'static Transform), (With<Person>, With<Interactable>)>;
+
+// 매시 정각마다 소문을 아는 사람이 가까이 있는 동료에게 옮김. 믿는 사이일수록 잘 옮김
+fn spread_rumors(
+    mut hour_started: EventReader<HourStarted>,
+    mut q_rumors: Query<&mut Rumor>,
+    mut q_knowers: Query<(&Transform, &mut RngStream, Option<&Relationships>), With<Person>>,
+    q_listeners: RumorListeners,
+    mut adjust: EventWriter<AdjustRelationship>,
+) {
+    if hour_started
+        .read()
+        .last()
+        .is_none()
+    {
+        return;
+    }
+
+    for mut rumor in q_rumors.iter_mut() {
+        // 이번 시간에 새로 들은 사람은 다음 시간부터 옮김
+        let mut heard: Vec<RumorKnower> = Vec::new();
+        for knower in rumor
+            .knowers
+            .iter()
+        {
+            let Ok((transform, mut rng, relationships)) = q_knowers.get_mut(knower.person) else {
+                continue;
+            };
+            for (listener, listener_transform) in q_listeners.iter() {
+                if !rumor.can_hear(listener)
+                    || heard
+                        .iter()
+                        .any(|other| other.person == listener)
+                    || transform
+                        .translation
+                        .distance(listener_transform.translation)
+                        > RUMOR_DISTANCE
+                {
+                    continue;
+                }
+                let trust = relationships.map_or(0, |relationships| {
+                    relationships.value(listener, RelationshipKind::Trust)
+                });
+                let chance = SPREAD_CHANCE + TRUST_SPREAD_CHANCE * trust as f64 / RELATIONSHIP_MAX as f64;
+                if rng.gen_bool(chance.clamp(0.0, 1.0)) {
+                    heard.push(pass_on(knower, listener, &mut rng));
+                }
+            }
+        }
+
+        for knower in heard {
+            adjust.send(AdjustRelationship {
+                entity: knower.person,
+                other: rumor.subject,
+                kind: RelationshipKind::Affinity,
+                amount: knower.affinity,
+            });
+            rumor
+                .knowers
+                .push(knower);
+        }
+    }
+}
+
+// 오래된 소문과 주인공이 사라진 소문은 잊힘
+fn forget_rumors(
+    mut commands: Commands,
+    mut day_started: EventReader<DayStarted>,
+    q_rumors: Query<(Entity, &Rumor)>,
+    q_people: Query<(), With<Person>>,
+) {
+    let Some(event) = day_started
+        .read()
+        .last()
+    else {
+        return;
+    };
+    for (entity, rumor) in q_rumors.iter() {
+        if event.day >= rumor.day + RUMOR_DAYS || !q_people.contains(rumor.subject) {
+            commands
+                .entity(entity)
+                .despawn();
+        }
+    }
+}
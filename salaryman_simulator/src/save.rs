@@ -0,0 +1,810 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy::scene::serde::{SceneDeserializer, SceneSerializer};
+use bevy::scene::{DynamicSceneBuilder, SceneSpawnError};
+use board_plugin::components::{BoardEntity, Coordinates};
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use thiserror::Error;
+
+use crate::actions::{Action, UiActionInput};
+use crate::career::{CareerRecord, Fired, JobFamily, Rank};
+use crate::clock::GameClock;
+use crate::components::{Desk, Interactable, Person, PlayTime, Worker};
+use crate::incidents::IncidentDirector;
+use crate::interaction::InteractionTargets;
+use crate::needs::{Burnout, Needs};
+use crate::payroll::{PayslipHistory, Wallet};
+use crate::player::Player;
+use crate::relationships::Relationships;
+use crate::rng::{RngStream, SimRng};
+use crate::ron_tree::{RonNode, RonTreeError};
+use crate::rumors::Rumor;
+use crate::tasks::{Manager, Performance, Skills, TaskList};
+use crate::tick::UiInputFocus;
+
+/// 저장 파일 버전. 저장되는 컴포넌트의 필드가 바뀌면 올리고 SaveMigrations에 변환 함수를 추가합니다.
+pub const SAVE_VERSION: u32 = 5;
+pub const SAVE_SLOT_COUNT: u8 = 3;
+const SAVE_DIRECTORY: &str = "saves";
+
+/// 이전 버전의 저장 파일을 한 버전 올리는 함수
+pub type SaveMigration = fn(&mut RonNode);
+
+/// 저장 파일 버전별 변환 함수. 키는 변환 전 버전입니다.
+#[derive(Resource, Default)]
+pub struct SaveMigrations(BTreeMap<u32, SaveMigration>);
+
+impl SaveMigrations {
+    pub fn add(&mut self, from_version: u32, migration: SaveMigration) -> &mut Self {
+        self.0
+            .insert(from_version, migration);
+        self
+    }
+
+    fn migrate(&self, version: u32, text: &mut String) -> Result<(), SaveError> {
+        if version >= SAVE_VERSION {
+            return Ok(());
+        }
+        let mut save = RonNode::parse(text)?;
+        for from_version in version..SAVE_VERSION {
+            let migration = self
+                .0
+                .get(&from_version)
+                .ok_or(SaveError::MissingMigration(from_version))?;
+            migration(&mut save);
+        }
+        save.insert_field("version", RonNode::atom(SAVE_VERSION));
+        *text = save.to_string();
+        Ok(())
+    }
+}
+
+/// 현재 선택된 저장 슬롯 (1부터 시작)
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+pub struct SaveSlot(pub u8);
+
+impl Default for SaveSlot {
+    fn default() -> Self {
+        SaveSlot(1)
+    }
+}
+
+impl fmt::Display for SaveSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SaveGame {
+    pub slot: u8,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LoadGame {
+    pub slot: u8,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SaveError {
+    #[error("Could not access save file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse save file: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("Could not write save file: {0}")]
+    Serialize(#[from] ron::Error),
+    #[error("Could not spawn saved entities: {0}")]
+    Spawn(#[from] SceneSpawnError),
+    #[error("Save file version {0} is newer than this game ({SAVE_VERSION})")]
+    UnsupportedVersion(u32),
+    #[error("No migration registered from save version {0}")]
+    MissingMigration(u32),
+    #[error("Could not migrate save file: {0}")]
+    Migrate(#[from] RonTreeError),
+}
+
+#[derive(Serialize)]
+struct SaveFile<'a> {
+    version: u32,
+    scene: SceneSerializer<'a>,
+}
+
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum SaveFileField {
+    Version,
+    Scene,
+}
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveMigrations>()
+            .init_resource::<SaveSlot>()
+            .add_event::<SaveGame>()
+            .add_event::<LoadGame>()
+            .add_systems(
+                Update,
+                (
+                    save_input.run_if(resource_equals(UiInputFocus(false))),
+                    handle_save_events,
+                )
+                    .chain(),
+            );
+        app.world
+            .resource_mut::<SaveMigrations>()
+            .add(1, migrate_v1_money)
+            .add(2, migrate_v2_interactions)
+            .add(3, migrate_v3_rng_positions)
+            .add(4, migrate_v4_incident_days);
+    }
+}
+
+/// 저장 파일의 모든 엔티티에서 같은 타입의 컴포넌트를 바꿈
+fn migrate_components(save: &mut RonNode, type_path: &str, mut migrate: impl FnMut(&mut RonNode)) {
+    let Some(entities) = save
+        .field_mut("scene")
+        .and_then(|scene| scene.field_mut("entities"))
+    else {
+        return;
+    };
+    for entity in entities.values_mut() {
+        if let Some(component) = entity
+            .field_mut("components")
+            .and_then(|components| components.entry_mut(type_path))
+        {
+            migrate(component);
+        }
+    }
+}
+
+fn saved_resources(save: &mut RonNode) -> Option<&mut RonNode> {
+    save.field_mut("scene")
+        .and_then(|scene| scene.field_mut("resources"))
+}
+
+/// 버전 1: 금액이 f32였음. 연봉은 만원 단위, 지갑과 명세서는 원 단위를 Money로 바꿈
+fn migrate_v1_money(save: &mut RonNode) {
+    const PAYSLIP_FIELDS: [&str; 7] = [
+        "gross",
+        "national_pension",
+        "health_insurance",
+        "long_term_care",
+        "employment_insurance",
+        "income_tax",
+        "local_income_tax",
+    ];
+
+    fn won(value: Option<&RonNode>, unit: f64) -> Option<RonNode> {
+        let won = value?.as_f64()? * unit;
+        Some(RonNode::fields(vec![
+            ("amount", RonNode::atom(won.round() as i64)),
+            ("currency", RonNode::atom("KRW")),
+        ]))
+    }
+
+    // Salary의 통화 문자열은 Money 안으로 들어감
+    migrate_components(save, "salaryman_simulator::components::Worker", |worker| {
+        let Some(salary) = worker.field_mut("salary") else {
+            return;
+        };
+        if let Some(amount) = won(salary.field("amount"), 10000.0) {
+            *salary = RonNode::fields(vec![("amount", amount)]);
+        }
+    });
+    migrate_components(save, "salaryman_simulator::payroll::Wallet", |wallet| {
+        if let Some(balance) = won(wallet.field("balance"), 1.0) {
+            wallet.insert_field("balance", balance);
+        }
+    });
+    migrate_components(save, "salaryman_simulator::payroll::PayslipHistory", |history| {
+        let Some(payslips) = history.field_mut("payslips") else {
+            return;
+        };
+        for payslip in payslips.items_mut() {
+            for field in PAYSLIP_FIELDS {
+                if let Some(amount) = won(payslip.field(field), 1.0) {
+                    payslip.insert_field(field, amount);
+                }
+            }
+        }
+    });
+}
+
+/// 버전 2: 상호작용이 종류 하나였음. 책상과 동료는 기본 행동 목록으로 바꾸고 나머지는 비움
+fn migrate_v2_interactions(save: &mut RonNode) {
+    migrate_components(save, "salaryman_simulator::components::Interactable", |interactable| {
+        let kind = interactable.remove_field("interaction_type");
+        let default = match kind {
+            Some(RonNode::Atom(kind)) if kind == "Work" => Interactable::desk(),
+            Some(RonNode::Atom(kind)) if kind == "SalaryMan" => Interactable::coworker(),
+            _ => Interactable::default(),
+        };
+        let actions = ron::to_string(&default.actions)
+            .ok()
+            .and_then(|actions| RonNode::parse(&actions).ok())
+            .unwrap_or(RonNode::List(Vec::new()));
+        interactable.insert_field("actions", actions);
+    });
+}
+
+/// 버전 3: 난수는 시드만 저장했음. 처음 위치부터 뽑도록 위치를 0으로 넣음
+fn migrate_v3_rng_positions(save: &mut RonNode) {
+    if let Some(sim_rng) = saved_resources(save).and_then(|resources| resources.entry_mut("salaryman_simulator::rng::SimRng")) {
+        sim_rng.insert_field("word_pos", RonNode::atom(0));
+        sim_rng.insert_field("stream_positions", RonNode::Map(Vec::new()));
+    }
+}
+
+/// 버전 4: 사건이 마지막으로 일어난 날을 저장하지 않았음. 아무 사건도 일어나지 않은 것으로 넣음
+fn migrate_v4_incident_days(save: &mut RonNode) {
+    if let Some(resources) = saved_resources(save) {
+        resources.insert_entry(
+            "salaryman_simulator::incidents::IncidentDirector",
+            RonNode::fields(vec![("last_days", RonNode::Map(Vec::new()))]),
+        );
+    }
+}
+
+pub fn slot_path(slot: u8) -> PathBuf {
+    PathBuf::from(SAVE_DIRECTORY).join(format!("slot_{}.ron", slot))
+}
+
+// 슬롯 선택 (기본 F1~F3), 저장 (기본 F5), 불러오기 (기본 F9)
+fn save_input(
+    actions: UiActionInput,
+    mut save_slot: ResMut<SaveSlot>,
+    mut save_events: EventWriter<SaveGame>,
+    mut load_events: EventWriter<LoadGame>,
+) {
+    for (index, action) in Action::SELECT_SLOTS
+        .iter()
+        .enumerate()
+        .take(SAVE_SLOT_COUNT as usize)
+    {
+        if actions.just_pressed(*action) {
+            save_slot.0 = index as u8 + 1;
+        }
+    }
+
+    if actions.just_pressed(Action::SaveGame) {
+        save_events.send(SaveGame { slot: save_slot.0 });
+    }
+    if actions.just_pressed(Action::LoadGame) {
+        load_events.send(LoadGame { slot: save_slot.0 });
+    }
+}
+
+// 월드 전체에 접근해야 하므로 실제 저장/불러오기는 커맨드로 처리
+fn handle_save_events(
+    mut commands: Commands,
+    mut save_events: EventReader<SaveGame>,
+    mut load_events: EventReader<LoadGame>,
+) {
+    for event in save_events.read() {
+        let slot = event.slot;
+        commands.add(move |world: &mut World| match save_to_slot(world, slot) {
+            Ok(()) => info!("Saved game to slot {}", slot),
+            Err(error) => error!("Could not save slot {}: {}", slot, error),
+        });
+    }
+    for event in load_events.read() {
+        let slot = event.slot;
+        commands.add(move |world: &mut World| match load_from_slot(world, slot) {
+            Ok(()) => info!("Loaded game from slot {}", slot),
+            Err(error) => error!("Could not load slot {}: {}", slot, error),
+        });
+    }
+}
+
+pub fn save_to_slot(world: &mut World, slot: u8) -> Result<(), SaveError> {
+    let text = save_to_string(world)?;
+    fs::create_dir_all(SAVE_DIRECTORY)?;
+    fs::write(slot_path(slot), text)?;
+    Ok(())
+}
+
+pub fn load_from_slot(world: &mut World, slot: u8) -> Result<(), SaveError> {
+    let text = fs::read_to_string(slot_path(slot))?;
+    load_from_str(world, text)
+}
+
+/// Person, Worker, Interactable, Player를 가진 엔티티, 소문과 플레이 시간, 게임 시계, 난수 위치, 사건이 일어난 날을 저장 파일 내용으로 만듦
+pub fn save_to_string(world: &mut World) -> Result<String, SaveError> {
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(With<Person>, With<Worker>, With<Interactable>, With<Player>, With<Rumor>)>>()
+        .iter(world)
+        .collect();
+    let streams: Vec<(String, u64)> = world
+        .query::<(&Name, &RngStream)>()
+        .iter(world)
+        .map(|(name, stream)| (name.to_string(), stream.word_pos()))
+        .collect();
+    world
+        .resource_mut::<SimRng>()
+        .record_positions(streams);
+
+    let scene = DynamicSceneBuilder::from_world(world)
+        .deny_all()
+        .allow::<Name>()
+        .allow::<Transform>()
+        .allow::<Person>()
+        .allow::<Worker>()
+        .allow::<Interactable>()
+        .allow::<Player>()
+        .allow::<Desk>()
+        .allow::<BoardEntity>()
+        .allow::<Coordinates>()
+        .allow::<Wallet>()
+        .allow::<PayslipHistory>()
+        .allow::<Needs>()
+        .allow::<Burnout>()
+        .allow::<TaskList>()
+        .allow::<Skills>()
+        .allow::<Performance>()
+        .allow::<Manager>()
+        .allow::<Rank>()
+        .allow::<JobFamily>()
+        .allow::<CareerRecord>()
+        .allow::<Fired>()
+        .allow::<Relationships>()
+        .allow::<Rumor>()
+        .deny_all_resources()
+        .allow_resource::<PlayTime>()
+        .allow_resource::<GameClock>()
+        .allow_resource::<SimRng>()
+        .allow_resource::<IncidentDirector>()
+        .extract_entities(entities.into_iter())
+        .extract_resources()
+        .build();
+    world
+        .resource_mut::<SimRng>()
+        .clear_stream_positions();
+
+    let registry = world.resource::<AppTypeRegistry>();
+    let save_file = SaveFile {
+        version: SAVE_VERSION,
+        scene: SceneSerializer::new(&scene, &registry.0),
+    };
+    Ok(ron::ser::to_string_pretty(&save_file, ron::ser::PrettyConfig::default())?)
+}
+
+/// 저장된 엔티티로 현재 월드의 사람, 책상, 플레이어, 소문을 교체
+pub fn load_from_str(world: &mut World, mut text: String) -> Result<(), SaveError> {
+    let header: SaveHeader = ron::de::from_str(&text)?;
+    if header.version > SAVE_VERSION {
+        return Err(SaveError::UnsupportedVersion(header.version));
+    }
+    world
+        .resource::<SaveMigrations>()
+        .migrate(header.version, &mut text)?;
+
+    let scene = {
+        let registry = world
+            .resource::<AppTypeRegistry>()
+            .read();
+        let mut deserializer = ron::de::Deserializer::from_str(&text)?;
+        SaveFileDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|error| deserializer.span_error(error))?
+    };
+
+    let old_entities: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(With<Person>, With<Worker>, With<Interactable>, With<Player>, With<Rumor>)>>()
+        .iter(world)
+        .collect();
+    for entity in old_entities {
+        despawn_with_children_recursive(world, entity);
+    }
+
+    // 저장된 맵은 기존 맵에 덮어써져서 저장 파일에 없는 사건이 남으므로 먼저 비움
+    if let Some(mut director) = world.get_resource_mut::<IncidentDirector>() {
+        director
+            .last_days
+            .clear();
+    }
+
+    let mut entity_map = EntityHashMap::default();
+    scene.write_to_world(world, &mut entity_map)?;
+
+    // 저장된 시드로 저장한 위치부터 이어서 뽑음. 사람마다의 스트림도 저장한 위치로 새 엔티티에 다시 붙음
+    world
+        .resource_mut::<SimRng>()
+        .reseed();
+
+    // 저장 파일에는 위치만 들어있으므로 렌더링에 필요한 컴포넌트를 다시 붙임. 위치가 없는 소문은 그대로 둠
+    for entity in entity_map.values() {
+        let Some(transform) = world
+            .get::<Transform>(*entity)
+            .copied()
+        else {
+            continue;
+        };
+        world
+            .entity_mut(*entity)
+            .insert(SpatialBundle::from_transform(transform));
+    }
+
+    // 사라진 엔티티를 가리키지 않도록 상호작용 대상을 비움
+    world
+        .resource_mut::<InteractionTargets>()
+        .replace(Vec::new());
+
+    Ok(())
+}
+
+struct SaveFileDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SaveFileDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("SaveFile", &["version", "scene"], self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SaveFileDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("save file")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut scene = None;
+        while let Some(key) = map.next_key::<SaveFileField>()? {
+            match key {
+                SaveFileField::Scene => {
+                    scene = Some(map.next_value_seed(SceneDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+                SaveFileField::Version => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        scene.ok_or_else(|| serde::de::Error::missing_field("scene"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::money::Money;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        crate::register_types(&mut app);
+        app.register_type::<Vec3>()
+            .register_type::<Quat>()
+            .register_type::<Name>()
+            .add_plugins(SavePlugin)
+            .insert_resource(SimRng::new(7))
+            .init_resource::<InteractionTargets>()
+            .init_resource::<PlayTime>()
+            .init_resource::<GameClock>()
+            .init_resource::<IncidentDirector>();
+        app
+    }
+
+    fn player_state(world: &mut World) -> (i32, i32, Money, Money, Vec3) {
+        let (person, worker, wallet, transform) = world
+            .query_filtered::<(&Person, &Worker, &Wallet, &Transform), With<Player>>()
+            .single(world);
+        (
+            person.hp,
+            person.san,
+            worker
+                .salary
+                .amount,
+            wallet.balance,
+            transform.translation,
+        )
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        let mut app = test_app();
+        app.world
+            .spawn((
+                Player,
+                Person {
+                    hp: 42,
+                    san: 17,
+                    ..default()
+                },
+                Worker {
+                    salary: crate::components::Salary {
+                        amount: Money::man_won(3200),
+                    },
+                    role: "개발자".to_string(),
+                },
+                Wallet {
+                    balance: Money::won(1_234_567),
+                },
+                Transform::from_xyz(12.0, -34.0, 0.0),
+            ));
+        app.world
+            .spawn((Desk, Interactable::desk(), Transform::from_xyz(64.0, 32.0, 0.0)));
+        app.world
+            .resource_mut::<GameClock>()
+            .elapsed_seconds = 123_456.0;
+        app.world
+            .resource_mut::<PlayTime>()
+            .0 = 99.0;
+        app.world
+            .resource_mut::<IncidentDirector>()
+            .last_days
+            .insert("printer_jam".to_string(), 3);
+        let saved = player_state(&mut app.world);
+
+        let text = save_to_string(&mut app.world).unwrap();
+        let header: SaveHeader = ron::de::from_str(&text).unwrap();
+        assert_eq!(header.version, SAVE_VERSION);
+
+        // 저장한 뒤에 바뀐 상태는 불러오면 되돌아감
+        for mut person in app
+            .world
+            .query::<&mut Person>()
+            .iter_mut(&mut app.world)
+        {
+            person.hp = 1;
+        }
+        app.world
+            .resource_mut::<GameClock>()
+            .elapsed_seconds = 0.0;
+        app.world
+            .insert_resource(SimRng::new(8));
+        app.world
+            .resource_mut::<IncidentDirector>()
+            .last_days
+            .insert("coffee_spill".to_string(), 4);
+
+        load_from_str(&mut app.world, text).unwrap();
+
+        assert_eq!(player_state(&mut app.world), saved);
+        assert_eq!(
+            app.world
+                .resource::<GameClock>()
+                .elapsed_seconds,
+            123_456.0
+        );
+        assert_eq!(
+            app.world
+                .resource::<PlayTime>()
+                .0,
+            99.0
+        );
+        assert_eq!(
+            app.world
+                .resource::<SimRng>()
+                .seed(),
+            7
+        );
+        assert_eq!(
+            app.world
+                .resource::<IncidentDirector>()
+                .last_days
+                .get("printer_jam"),
+            Some(&3)
+        );
+        assert!(!app
+            .world
+            .resource::<IncidentDirector>()
+            .last_days
+            .contains_key("coffee_spill"));
+        let desks: Vec<Vec<crate::interaction::InteractionAction>> = app
+            .world
+            .query_filtered::<&Interactable, With<Desk>>()
+            .iter(&app.world)
+            .map(|interactable| {
+                interactable
+                    .actions
+                    .clone()
+            })
+            .collect();
+        assert_eq!(desks, vec![Interactable::desk().actions]);
+    }
+
+    #[test]
+    fn rng_continues_after_load() {
+        use rand::RngCore;
+
+        let mut app = test_app();
+        let mut stream = app
+            .world
+            .resource::<SimRng>()
+            .fork("김대리");
+        stream.next_u64();
+        app.world
+            .spawn((Person::default(), Name::new("김대리"), stream));
+        app.world
+            .resource_mut::<SimRng>()
+            .next_u64();
+
+        let text = save_to_string(&mut app.world).unwrap();
+        let expected_stream = app
+            .world
+            .query::<&mut RngStream>()
+            .single_mut(&mut app.world)
+            .next_u64();
+        let expected = app
+            .world
+            .resource_mut::<SimRng>()
+            .next_u64();
+
+        load_from_str(&mut app.world, text).unwrap();
+
+        let mut sim_rng = app
+            .world
+            .resource_mut::<SimRng>();
+        assert_eq!(sim_rng.next_u64(), expected);
+        assert_eq!(
+            sim_rng
+                .resume("김대리")
+                .next_u64(),
+            expected_stream
+        );
+    }
+
+    #[test]
+    fn newer_save_version_is_rejected() {
+        let mut app = test_app();
+        let text = format!("(version: {}, scene: (resources: {{}}, entities: {{}}))", SAVE_VERSION + 1);
+        assert!(matches!(
+            load_from_str(&mut app.world, text),
+            Err(SaveError::UnsupportedVersion(version)) if version == SAVE_VERSION + 1
+        ));
+    }
+
+    // 버전 1과 2 저장 파일은 같은 사람들을 담고 있어서 변환 결과도 같아야 함
+    fn assert_migrated_people(world: &mut World) {
+        let (hp, san, salary, balance, translation) = player_state(world);
+        assert_eq!((hp, san), (80, 65));
+        assert_eq!(salary, Money::man_won(3000));
+        assert_eq!(balance, Money::won(2_234_567));
+        assert_eq!(translation, Vec3::new(10.0, -20.0, 0.0));
+
+        let (worker, interactable) = world
+            .query_filtered::<(&Worker, &Interactable), (With<Person>, Without<Player>)>()
+            .single(world);
+        assert_eq!(
+            worker
+                .salary
+                .amount,
+            Money::won(45_005_000)
+        );
+        assert_eq!(interactable.actions, Interactable::coworker().actions);
+
+        let (interactable, coordinates) = world
+            .query_filtered::<(&Interactable, &Coordinates), With<Desk>>()
+            .single(world);
+        assert_eq!(interactable.actions, Interactable::desk().actions);
+        assert_eq!(*coordinates, Coordinates::new(2, 5));
+
+        assert_eq!(
+            world
+                .resource::<PlayTime>()
+                .0,
+            125.5
+        );
+        assert_eq!(
+            world
+                .resource::<GameClock>()
+                .elapsed_seconds,
+            2_332_800.0
+        );
+    }
+
+    #[test]
+    fn load_v1_save_migrates_money_and_interactions() {
+        let mut app = test_app();
+        load_from_str(&mut app.world, include_str!("../tests/fixtures/save_v1.ron").to_string()).unwrap();
+        assert_migrated_people(&mut app.world);
+
+        let payslip = app
+            .world
+            .query::<&PayslipHistory>()
+            .single(&app.world)
+            .payslips[0]
+            .clone();
+        assert_eq!(payslip.gross, Money::won(2_500_000));
+        assert_eq!(payslip.local_income_tax, Money::won(4_160));
+        assert_eq!(
+            payslip.deductions(),
+            Ok(Money::won(112_500 + 88_625 + 11_480 + 22_500 + 41_630 + 4_160))
+        );
+    }
+
+    #[test]
+    fn load_v2_save_migrates_interactions() {
+        let mut app = test_app();
+        load_from_str(&mut app.world, include_str!("../tests/fixtures/save_v2.ron").to_string()).unwrap();
+        assert_migrated_people(&mut app.world);
+        assert_eq!(
+            app.world
+                .resource::<SimRng>()
+                .seed(),
+            42
+        );
+        assert!(app
+            .world
+            .resource::<IncidentDirector>()
+            .last_days
+            .is_empty());
+    }
+
+    #[test]
+    fn migrate_v1_money_converts_only_money_fields() {
+        let mut save = RonNode::parse(
+            r#"(version: 1, scene: (resources: {}, entities: {1: (components: {
+                "salaryman_simulator::components::Worker": (salary: (amount: 1234.5, currency: "KRW"), role: "개발"),
+                "salaryman_simulator::payroll::Wallet": (balance: 10.0),
+                "some_mod::Other": (amount: 2.5, currency: "KRW"),
+            })}))"#,
+        )
+        .unwrap();
+        migrate_v1_money(&mut save);
+
+        let components = save
+            .field_mut("scene")
+            .and_then(|scene| scene.field_mut("entities"))
+            .and_then(|entities| {
+                entities
+                    .values_mut()
+                    .next()
+            })
+            .and_then(|entity| entity.field_mut("components"))
+            .unwrap();
+        let component = |components: &mut RonNode, type_path: &str| {
+            components
+                .entry_mut(type_path)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(
+            component(components, "salaryman_simulator::components::Worker"),
+            "(salary:(amount:(amount:12345000,currency:KRW,),),role:\"개발\",)"
+        );
+        assert_eq!(
+            component(components, "salaryman_simulator::payroll::Wallet"),
+            "(balance:(amount:10,currency:KRW,),)"
+        );
+        assert_eq!(component(components, "some_mod::Other"), "(amount:2.5,currency:\"KRW\",)");
+    }
+
+    #[test]
+    fn migrate_v4_adds_incident_days_to_compact_saves() {
+        let mut save = RonNode::parse("(version:4,scene:(resources:{},entities:{}))").unwrap();
+        migrate_v4_incident_days(&mut save);
+        assert_eq!(
+            save.to_string(),
+            "(version:4,scene:(resources:{\"salaryman_simulator::incidents::IncidentDirector\":(last_days:{},),},entities:{},),)"
+        );
+    }
+}
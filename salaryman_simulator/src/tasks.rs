@@ -0,0 +1,507 @@
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::clock::{GameClock, GameDate, HourStarted, Weekday, WorkHoursEnded};
+use crate::components::{Desk, Worker};
+use crate::needs::{Burnout, Needs, NeedsRestore, RestoreNeeds};
+use crate::rng::RngStream;
+
+/// 한 사람이 동시에 맡을 수 있는 업무 수
+const MAX_OPEN_TASKS: usize = 3;
+/// 책상에서 이만큼 멀어지면 일을 멈춤
+pub const WORK_DISTANCE: f32 = 60.0;
+/// 업무를 끝낼 때마다 오르는 숙련도
+const SKILL_GAIN: f32 = 0.05;
+const MAX_SKILL: f32 = 2.0;
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Skill {
+    Development,
+    Design,
+    Sales,
+    Paperwork,
+}
+
+impl Skill {
+    /// 직무에 맞는 주 기술
+    pub fn for_role(role: &str) -> Self {
+        match role {
+            "개발" => Skill::Development,
+            "디자인" => Skill::Design,
+            "영업" => Skill::Sales,
+            _ => Skill::Paperwork,
+        }
+    }
+}
+
+/// 기술별 숙련도. 1이면 보통입니다.
+#[derive(Reflect, Component, Debug, Clone)]
+#[reflect(Component)]
+pub struct Skills {
+    pub development: f32,
+    pub design: f32,
+    pub sales: f32,
+    pub paperwork: f32,
+}
+
+impl Default for Skills {
+    fn default() -> Self {
+        Skills {
+            development: 0.5,
+            design: 0.5,
+            sales: 0.5,
+            paperwork: 0.5,
+        }
+    }
+}
+
+impl Skills {
+    /// 주 기술만 숙련도가 1인 신입
+    pub fn for_role(role: &str) -> Self {
+        let mut skills = Skills::default();
+        *skills.level_mut(Skill::for_role(role)) = 1.0;
+        skills
+    }
+
+    pub fn level(&self, skill: Skill) -> f32 {
+        match skill {
+            Skill::Development => self.development,
+            Skill::Design => self.design,
+            Skill::Sales => self.sales,
+            Skill::Paperwork => self.paperwork,
+        }
+    }
+
+    pub fn level_mut(&mut self, skill: Skill) -> &mut f32 {
+        match skill {
+            Skill::Development => &mut self.development,
+            Skill::Design => &mut self.design,
+            Skill::Sales => &mut self.sales,
+            Skill::Paperwork => &mut self.paperwork,
+        }
+    }
+}
+
+#[derive(Reflect, Debug, Clone)]
+pub struct Task {
+    pub title: String,
+    /// 숙련도 1, 효율 1로 끝내는 데 걸리는 게임 시간
+    pub difficulty: f32,
+    pub skill: Skill,
+    /// 이 날 퇴근 시간까지 끝내야 함
+    pub deadline_day: u32,
+    pub progress: f32,
+}
+
+impl Task {
+    /// 진행률 (0 ~ 1)
+    pub fn ratio(&self) -> f32 {
+        (self.progress / self.difficulty).clamp(0.0, 1.0)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress >= self.difficulty
+    }
+
+    pub fn deadline(&self) -> GameDate {
+        GameDate::from_day(self.deadline_day)
+    }
+}
+
+/// 맡은 업무 목록. 마감이 빠른 업무부터 진행합니다.
+#[derive(Reflect, Component, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct TaskList {
+    pub tasks: Vec<Task>,
+}
+
+impl TaskList {
+    pub fn current(&self) -> Option<&Task> {
+        self.current_index()
+            .map(|index| &self.tasks[index])
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut Task> {
+        self.current_index()
+            .map(|index| &mut self.tasks[index])
+    }
+
+    fn current_index(&self) -> Option<usize> {
+        self.tasks
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, task)| task.deadline_day)
+            .map(|(index, _)| index)
+    }
+}
+
+/// 업무 성과. 인사 평가에 쓰입니다.
+#[derive(Reflect, Component, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct Performance {
+    pub completed: u32,
+    pub missed: u32,
+}
+
+/// 업무를 나눠주는 관리자
+#[derive(Reflect, Component, Default, Debug)]
+#[reflect(Component)]
+pub struct Manager;
+
+/// 책상에 앉아 일하는 중
+#[derive(Component, Debug)]
+pub struct Working {
+    pub desk: Entity,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct TaskAssigned {
+    pub manager: Entity,
+    pub worker: Entity,
+    pub task: Task,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct TaskCompleted {
+    pub worker: Entity,
+    pub task: Task,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct TaskMissed {
+    pub worker: Entity,
+    pub task: Task,
+}
+
+/// `helper`가 `worker`의 지금 업무를 게임 시간 `hours`만큼 대신 해줌. 둘이 같으면 자기 업무를 진행
+#[derive(Event, Debug, Clone, Copy)]
+pub struct HelpWithTask {
+    pub helper: Entity,
+    pub worker: Entity,
+    pub hours: f32,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct TaskHelped {
+    pub helper: Entity,
+    pub worker: Entity,
+    pub task: Task,
+}
+
+/// 관리자가 나눠주는 업무의 종류
+#[derive(Reflect, Debug, Clone)]
+pub struct TaskTemplate {
+    pub title: String,
+    /// 난이도 범위 (게임 시간)
+    pub difficulty: (f32, f32),
+    /// None이면 받는 사람의 주 기술
+    pub skill: Option<Skill>,
+    /// 마감까지 남은 날 범위
+    pub days: (u32, u32),
+}
+
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct TaskConfig {
+    pub templates: Vec<TaskTemplate>,
+}
+
+impl Default for TaskConfig {
+    fn default() -> Self {
+        let template = |title: &str, difficulty: (f32, f32), skill: Option<Skill>, days: (u32, u32)| {
+            TaskTemplate {
+                title: title.to_string(),
+                difficulty,
+                skill,
+                days,
+            }
+        };
+        TaskConfig {
+            templates: vec![
+                template("주간 보고서", (2.0, 4.0), Some(Skill::Paperwork), (1, 2)),
+                template("회의록 정리", (1.0, 2.0), Some(Skill::Paperwork), (0, 1)),
+                template("담당 업무", (4.0, 10.0), None, (1, 3)),
+                template("긴급 요청", (2.0, 5.0), None, (0, 0)),
+            ],
+        }
+    }
+}
+
+pub struct TaskPlugin;
+
+impl Plugin for TaskPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TaskConfig>()
+            .add_event::<TaskAssigned>()
+            .add_event::<TaskCompleted>()
+            .add_event::<TaskMissed>()
+            .add_event::<HelpWithTask>()
+            .add_event::<TaskHelped>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    add_task_lists,
+                    assign_tasks,
+                    stop_working,
+                    help_with_tasks,
+                    work_on_tasks,
+                    miss_deadlines,
+                    record_performance,
+                )
+                    .chain()
+                    .in_set(crate::WorldUpdateSet),
+            );
+    }
+}
+
+// 관리자가 아닌 직장인에게 업무 목록, 숙련도, 성과를 줌 (새로 생성되거나 예전 저장 파일에서 불러온 경우)
+fn add_task_lists(
+    mut commands: Commands,
+    q_workers: Query<(Entity, &Worker, Has<Manager>), Without<TaskList>>,
+) {
+    for (entity, worker, is_manager) in q_workers.iter() {
+        if is_manager {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert((
+                TaskList::default(),
+                Skills::for_role(&worker.role),
+                Performance::default(),
+            ));
+    }
+}
+
+// 평일 출근 시간마다 관리자가 업무가 적은 사람에게 새 업무를 줌. 업무 내용은 받는 사람의 난수 스트림으로 뽑음
+fn assign_tasks(
+    mut hour_started: EventReader<HourStarted>,
+    clock: Res<GameClock>,
+    config: Res<TaskConfig>,
+    q_managers: Query<(Entity, &Name), With<Manager>>,
+    mut q_workers: Query<(Entity, &Name, &Worker, &mut TaskList, &mut RngStream)>,
+    mut assigned: EventWriter<TaskAssigned>,
+) {
+    for event in hour_started.read() {
+        if event.hour != clock.work_start_hour || Weekday::from_day(event.day).is_weekend() {
+            continue;
+        }
+        let Some((manager, manager_name)) = q_managers
+            .iter()
+            .next()
+        else {
+            continue;
+        };
+
+        for (worker, name, job, mut task_list, mut rng) in q_workers.iter_mut() {
+            if task_list
+                .tasks
+                .len()
+                >= MAX_OPEN_TASKS
+            {
+                continue;
+            }
+            let Some(template) = config
+                .templates
+                .choose(&mut *rng)
+            else {
+                continue;
+            };
+            let task = Task {
+                title: template
+                    .title
+                    .clone(),
+                difficulty: rng.gen_range(template.difficulty.0..=template.difficulty.1),
+                skill: template
+                    .skill
+                    .unwrap_or(Skill::for_role(&job.role)),
+                deadline_day: event.day + rng.gen_range(template.days.0..=template.days.1),
+                progress: 0.0,
+            };
+            info!(
+                "{} → {}: {} (마감 {})",
+                manager_name.as_str(),
+                name.as_str(),
+                task.title,
+                task.deadline()
+            );
+            task_list
+                .tasks
+                .push(task.clone());
+            assigned.send(TaskAssigned {
+                manager,
+                worker,
+                task,
+            });
+        }
+    }
+}
+
+// 책상에서 멀어지면 일을 멈춤
+fn stop_working(
+    mut commands: Commands,
+    q_working: Query<(Entity, &Transform, &Working)>,
+    q_desks: Query<&Transform, With<Desk>>,
+) {
+    for (entity, transform, working) in q_working.iter() {
+        let near_desk = q_desks
+            .get(working.desk)
+            .is_ok_and(|desk| {
+                desk.translation
+                    .distance(transform.translation)
+                    < WORK_DISTANCE
+            });
+        if !near_desk {
+            commands
+                .entity(entity)
+                .remove::<Working>();
+        }
+    }
+}
+
+/// 업무를 진행하는 직장인
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct TaskWorker {
+    entity: Entity,
+    task_list: &'static mut TaskList,
+    skills: &'static mut Skills,
+    needs: Option<&'static Needs>,
+    burned_out: Has<Burnout>,
+    working: Has<Working>,
+}
+
+// 책상에 앉아 있는 동안 업무를 진행. 근무 시간이 지나도 책상에 있으면 야근
+fn work_on_tasks(
+    time: Res<Time>,
+    clock: Res<GameClock>,
+    mut q_workers: Query<TaskWorker>,
+    mut completed: EventWriter<TaskCompleted>,
+) {
+    let hours = clock.scaled_delta_hours(&time);
+
+    for mut worker in q_workers.iter_mut() {
+        if !worker.working || worker.burned_out {
+            continue;
+        }
+        let efficiency = worker
+            .needs
+            .map_or(1.0, |needs| needs.work_efficiency());
+        let Some(index) = worker
+            .task_list
+            .current_index()
+        else {
+            continue;
+        };
+        let skill_level = worker
+            .skills
+            .level(worker.task_list.tasks[index].skill);
+        let task = &mut worker
+            .task_list
+            .tasks[index];
+        task.progress += hours * efficiency * skill_level;
+        if !task.is_done() {
+            continue;
+        }
+
+        let task = worker
+            .task_list
+            .tasks
+            .remove(index);
+        let level = worker
+            .skills
+            .level_mut(task.skill);
+        *level = (*level + SKILL_GAIN).min(MAX_SKILL);
+        completed.send(TaskCompleted {
+            worker: worker.entity,
+            task,
+        });
+    }
+}
+
+// 도와주는 사람의 숙련도로 상대의 지금 업무를 진행. 도와주는 사람은 일한 만큼 지침
+fn help_with_tasks(
+    mut events: EventReader<HelpWithTask>,
+    q_skills: Query<&Skills>,
+    mut q_task_lists: Query<&mut TaskList>,
+    mut completed: EventWriter<TaskCompleted>,
+    mut helped: EventWriter<TaskHelped>,
+    mut restore: EventWriter<RestoreNeeds>,
+) {
+    for event in events.read() {
+        let Ok(mut task_list) = q_task_lists.get_mut(event.worker) else {
+            continue;
+        };
+        let Some(index) = task_list.current_index() else {
+            continue;
+        };
+        // 업무를 받지 않는 관리자도 보통 숙련도로 도울 수 있음
+        let skill_level = q_skills
+            .get(event.helper)
+            .map_or(1.0, |skills| skills.level(task_list.tasks[index].skill));
+        let task = &mut task_list.tasks[index];
+        task.progress += event.hours * skill_level;
+        // 책상 밖에서 자기 업무를 진행한 경우는 도움이 아님
+        if event.helper != event.worker {
+            helped.send(TaskHelped {
+                helper: event.helper,
+                worker: event.worker,
+                task: task.clone(),
+            });
+        }
+        restore.send(RestoreNeeds {
+            entity: event.helper,
+            source: NeedsRestore::Work,
+        });
+        if task.is_done() {
+            let task = task_list
+                .tasks
+                .remove(index);
+            completed.send(TaskCompleted {
+                worker: event.worker,
+                task,
+            });
+        }
+    }
+}
+
+// 퇴근 시간까지 끝내지 못한 마감 업무는 놓친 것으로 처리
+fn miss_deadlines(
+    mut work_hours_ended: EventReader<WorkHoursEnded>,
+    mut q_workers: Query<(Entity, &mut TaskList)>,
+    mut missed: EventWriter<TaskMissed>,
+) {
+    for event in work_hours_ended.read() {
+        for (worker, mut task_list) in q_workers.iter_mut() {
+            let (late, open): (Vec<Task>, Vec<Task>) = task_list
+                .tasks
+                .drain(..)
+                .partition(|task| task.deadline_day <= event.day);
+            task_list.tasks = open;
+            for task in late {
+                missed.send(TaskMissed { worker, task });
+            }
+        }
+    }
+}
+
+fn record_performance(
+    mut completed: EventReader<TaskCompleted>,
+    mut missed: EventReader<TaskMissed>,
+    mut q_performance: Query<(&Name, &mut Performance)>,
+) {
+    for event in completed.read() {
+        if let Ok((name, mut performance)) = q_performance.get_mut(event.worker) {
+            performance.completed += 1;
+            info!("{} 업무 완료: {}", name.as_str(), event.task.title);
+        }
+    }
+    for event in missed.read() {
+        if let Ok((name, mut performance)) = q_performance.get_mut(event.worker) {
+            performance.missed += 1;
+            info!("{} 마감 놓침: {}", name.as_str(), event.task.title);
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use std::hash::Hash;
+use std::time::Duration;
+
+use bevy::ecs::schedule::ExecutorKind;
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use board_plugin::resources::TileMap;
+
+/// 기본 시뮬레이션 틱 속도 (초당 틱 수)
+pub const DEFAULT_TICK_RATE: f64 = 60.0;
+
+/// 시뮬레이션 틱 속도. 화면 주사율과 상관없이 초당 이만큼 FixedUpdate를 실행합니다.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TickRate(pub f64);
+
+impl Default for TickRate {
+    fn default() -> Self {
+        TickRate(DEFAULT_TICK_RATE)
+    }
+}
+
+impl TickRate {
+    /// 틱 한 번의 길이. 나노초 단위로 내림해서, 1초를 나눈 프레임들을 더해도 틱이 하나 모자라지 않음
+    pub fn timestep(&self) -> Duration {
+        Duration::from_nanos((1_000_000_000.0 / self.0) as u64)
+    }
+}
+
+/// 배치도가 로드된 뒤로 지난 틱 수
+///
+/// 로딩이 실행마다 얼마나 걸리든 같은 틱 번호는 같은 시뮬레이션 시점입니다.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SimTick(pub u64);
+
+/// 틱 단위로 본 입력 상태
+///
+/// 프레임마다 들어온 입력을 모아 두었다가 다음 틱에 넘기므로, 틱이 돌지 않은 프레임의
+/// `just_pressed`를 놓치거나 한 프레임에 틱이 여러 번 돌 때 두 번 처리하지 않습니다.
+#[derive(Resource, Debug, Clone, Deref, DerefMut)]
+pub struct TickInput<T: Copy + Eq + Hash + Send + Sync + 'static>(ButtonInput<T>);
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> Default for TickInput<T> {
+    fn default() -> Self {
+        TickInput(ButtonInput::default())
+    }
+}
+
+/// 키 설정 화면처럼 UI가 입력을 쓰는 중인지
+///
+/// 켜져 있는 동안은 입력을 틱에 넘기지 않아서 키를 누르는 대로 플레이어가 움직이지 않습니다.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiInputFocus(pub bool);
+
+/// 고정 틱 사이에 화면에 그릴 위치를 보간하는 엔티티
+///
+/// 시뮬레이션은 틱마다 Transform을 옮기고, 화면에는 직전 틱과 마지막 틱 사이의 위치를 그립니다.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TransformInterpolation {
+    previous: Vec3,
+    current: Vec3,
+    /// 마지막으로 화면에 그린 위치. Transform이 이 값이 아니면 누군가 직접 옮긴 것
+    rendered: Vec3,
+}
+
+impl TransformInterpolation {
+    pub fn new(translation: Vec3) -> Self {
+        TransformInterpolation {
+            previous: translation,
+            current: translation,
+            rendered: translation,
+        }
+    }
+
+    /// 보간하지 않은 마지막 틱의 위치
+    pub fn tick_translation(&self) -> Vec3 {
+        self.current
+    }
+}
+
+/// 고정 틱 속도와 틱 단위 입력 (시뮬레이션)
+pub struct TickPlugin;
+
+impl Plugin for TickPlugin {
+    fn build(&self, app: &mut App) {
+        let tick_rate = *app
+            .world
+            .get_resource_or_insert_with(TickRate::default);
+        // 같은 입력이면 같은 결과가 나오도록 틱 안의 시스템은 항상 같은 순서로 하나씩 실행
+        app.edit_schedule(FixedUpdate, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+        app.insert_resource(Time::<Fixed>::from_duration(tick_rate.timestep()))
+            .init_resource::<SimTick>()
+            .init_resource::<TickInput<KeyCode>>()
+            .init_resource::<TickInput<MouseButton>>()
+            .init_resource::<TickInput<GamepadButtonType>>()
+            .init_resource::<UiInputFocus>()
+            .add_systems(
+                PreUpdate,
+                (
+                    collect_tick_input::<KeyCode>,
+                    collect_tick_input::<MouseButton>,
+                    collect_gamepad_tick_input,
+                )
+                    .after(InputSystem),
+            )
+            .add_systems(
+                FixedLast,
+                (
+                    clear_tick_input::<KeyCode>,
+                    clear_tick_input::<MouseButton>,
+                    clear_tick_input::<GamepadButtonType>,
+                    count_ticks.run_if(resource_exists::<TileMap>),
+                ),
+            );
+    }
+}
+
+/// 틱 사이의 Transform 보간 (화면 출력)
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedFirst, restore_tick_transforms)
+            .add_systems(FixedLast, record_tick_transforms)
+            .add_systems(
+                PostUpdate,
+                interpolate_transforms.before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+// 이번 프레임에 들어온 입력을 다음 틱으로 넘김
+fn collect_tick_input<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    focus: Res<UiInputFocus>,
+    input: Res<ButtonInput<T>>,
+    mut tick_input: ResMut<TickInput<T>>,
+) {
+    if focus.0 {
+        mirror_buttons(&mut tick_input, &[], &[]);
+        return;
+    }
+    let pressed: Vec<T> = input
+        .get_just_pressed()
+        .chain(input.get_pressed())
+        .copied()
+        .collect();
+    let held: Vec<T> = input
+        .get_pressed()
+        .copied()
+        .collect();
+    mirror_buttons(&mut tick_input, &pressed, &held);
+}
+
+// 혼자 하는 게임이라 어느 게임패드로 눌렀는지는 구분하지 않음
+fn collect_gamepad_tick_input(
+    focus: Res<UiInputFocus>,
+    input: Res<ButtonInput<GamepadButton>>,
+    mut tick_input: ResMut<TickInput<GamepadButtonType>>,
+) {
+    if focus.0 {
+        mirror_buttons(&mut tick_input, &[], &[]);
+        return;
+    }
+    let pressed: Vec<GamepadButtonType> = input
+        .get_just_pressed()
+        .chain(input.get_pressed())
+        .map(|button| button.button_type)
+        .collect();
+    let held: Vec<GamepadButtonType> = input
+        .get_pressed()
+        .map(|button| button.button_type)
+        .collect();
+    mirror_buttons(&mut tick_input, &pressed, &held);
+}
+
+/// `pressed`를 누르고 `held`에 없는 버튼은 뗌
+fn mirror_buttons<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    tick_input: &mut ButtonInput<T>,
+    pressed: &[T],
+    held: &[T],
+) {
+    // 한 프레임 안에 눌렀다 뗀 버튼도 just_pressed는 남도록 누른 것부터 처리
+    for button in pressed {
+        if !tick_input.pressed(*button) {
+            tick_input.press(*button);
+        }
+    }
+    let released: Vec<T> = tick_input
+        .get_pressed()
+        .filter(|button| !held.contains(button))
+        .copied()
+        .collect();
+    for button in released {
+        tick_input.release(button);
+    }
+}
+
+// 틱이 끝나면 just_pressed, just_released를 비움
+fn clear_tick_input<T: Copy + Eq + Hash + Send + Sync + 'static>(mut tick_input: ResMut<TickInput<T>>) {
+    tick_input.clear();
+}
+
+fn count_ticks(mut tick: ResMut<SimTick>) {
+    tick.0 += 1;
+}
+
+// 틱을 시작하기 전에 화면용으로 보간한 위치를 시뮬레이션 위치로 되돌림
+fn restore_tick_transforms(mut query: Query<(&mut Transform, &mut TransformInterpolation)>) {
+    for (mut transform, mut interpolation) in query.iter_mut() {
+        if transform.translation == interpolation.rendered {
+            transform.translation = interpolation.current;
+        } else {
+            // 불러오기처럼 틱 밖에서 옮긴 위치는 그대로 받아들임
+            interpolation.current = transform.translation;
+        }
+        interpolation.previous = interpolation.current;
+    }
+}
+
+fn record_tick_transforms(mut query: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in query.iter_mut() {
+        interpolation.current = transform.translation;
+    }
+}
+
+// 다음 틱까지 남은 시간만큼 직전 틱과 마지막 틱 사이를 보간
+fn interpolate_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut TransformInterpolation)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolation) in query.iter_mut() {
+        // 틱 밖에서 옮겼으면 보간하지 않고 그 자리에 그림
+        if transform.translation != interpolation.rendered && transform.translation != interpolation.current {
+            *interpolation = TransformInterpolation::new(transform.translation);
+            continue;
+        }
+        transform.translation = interpolation
+            .previous
+            .lerp(interpolation.current, alpha);
+        interpolation.rendered = transform.translation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::InputPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::actions::{ActionMap, ActionsPlugin};
+    use crate::clock::{ClockPlugin, ClockSet, GameClock};
+    use crate::components::Person;
+    use crate::player::{player_movement, Player};
+
+    /// 1초 동안 같은 입력을 주고 끝난 뒤의 틱 수, 게임 시계, 플레이어의 틱 위치
+    fn run_at_frame_rate(frames_per_second: u32) -> (u64, f64, Vec3) {
+        let mut app = App::new();
+        app.insert_resource(ActionMap::default())
+            .add_plugins((
+                MinimalPlugins,
+                InputPlugin,
+                ActionsPlugin,
+                TickPlugin,
+                InterpolationPlugin,
+                ClockPlugin,
+            ))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                Duration::from_secs(1) / frames_per_second,
+            ))
+            .insert_resource(TileMap::office(20, 15, 32.0))
+            .add_systems(FixedUpdate, player_movement.after(ClockSet));
+        let player = app
+            .world
+            .spawn((
+                Player,
+                Person::default(),
+                Transform::default(),
+                TransformInterpolation::new(Vec3::ZERO),
+            ))
+            .id();
+        // 첫 업데이트는 시간이 흐르지 않음
+        app.update();
+
+        // 0.5초 동안 오른쪽으로, 다음 0.5초 동안 위로 걸어감
+        for frame in 0..frames_per_second {
+            let mut keys = app
+                .world
+                .resource_mut::<ButtonInput<KeyCode>>();
+            if frame == 0 {
+                keys.press(KeyCode::KeyD);
+            }
+            if frame == frames_per_second / 2 {
+                keys.release(KeyCode::KeyD);
+                keys.press(KeyCode::KeyW);
+            }
+            app.update();
+        }
+
+        let tick = app
+            .world
+            .resource::<SimTick>()
+            .0;
+        let elapsed_seconds = app
+            .world
+            .resource::<GameClock>()
+            .elapsed_seconds;
+        let translation = app
+            .world
+            .get::<TransformInterpolation>(player)
+            .unwrap()
+            .tick_translation();
+        (tick, elapsed_seconds, translation)
+    }
+
+    #[test]
+    fn same_input_gives_same_result_at_any_frame_rate() {
+        let slow = run_at_frame_rate(30);
+        let fast = run_at_frame_rate(120);
+        assert_eq!(slow.0, DEFAULT_TICK_RATE as u64);
+        assert_eq!(slow, fast);
+        assert!(slow.2.x > 0.0 && slow.2.y > 0.0, "{:?}", slow.2);
+    }
+
+    #[test]
+    fn button_pressed_and_released_within_a_frame_reaches_the_tick() {
+        let mut tick_input = ButtonInput::<KeyCode>::default();
+        mirror_buttons(&mut tick_input, &[KeyCode::KeyE], &[]);
+        assert!(tick_input.just_pressed(KeyCode::KeyE));
+        assert!(!tick_input.pressed(KeyCode::KeyE));
+    }
+}